@@ -0,0 +1,59 @@
+use near_sdk::{
+	near_bindgen,
+	json_types::U64,
+	borsh::{
+		self,
+		BorshDeserialize,
+		BorshSerialize
+	}
+};
+use std::collections::HashMap;
+
+#[global_allocator]
+static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
+
+/**
+ * @notice Mock oracle used to test Flux Protocol's oracle adapter, anyone can report an outcome
+ */
+#[near_bindgen]
+#[derive(Default, BorshDeserialize, BorshSerialize)]
+struct MockOracle {
+	outcomes: HashMap<u64, Option<u64>>, // Maps market_id => reported outcome, None means the market is invalid
+}
+
+#[near_bindgen]
+impl MockOracle {
+	/**
+	 * @notice Reports the outcome of a market
+	 * @param market_id The id of the market in the Flux Protocol contract
+	 * @param winning_outcome The winning outcome, None if the market should resolute as invalid
+	 */
+	pub fn report(
+		&mut self,
+		market_id: U64,
+		winning_outcome: Option<U64>
+	) {
+		let winning_outcome: Option<u64> = match winning_outcome {
+			Some(outcome) => Some(outcome.into()),
+			None => None
+		};
+		self.outcomes.insert(market_id.into(), winning_outcome);
+	}
+
+	/**
+	 * @notice Returns the reported outcome of a market
+	 * @dev Panics if no outcome was reported for the market yet
+	 * @return Returns the winning outcome, None if the market should resolute as invalid
+	 */
+	pub fn get_outcome(
+		&self,
+		market_id: U64
+	) -> Option<U64> {
+		let market_id: u64 = market_id.into();
+		let outcome = self.outcomes.get(&market_id).expect("outcome not reported");
+		return match outcome {
+			Some(outcome) => Some(U64(*outcome)),
+			None => None
+		};
+	}
+}
@@ -1,2 +1,4 @@
 RUSTFLAGS='-C link-arg=-s' cargo +stable build --target wasm32-unknown-unknown --release
 cp target/wasm32-unknown-unknown/release/flux_protocol.wasm ./res/
+(cd mock_oracle && RUSTFLAGS='-C link-arg=-s' cargo +stable build --target wasm32-unknown-unknown --release)
+cp mock_oracle/target/wasm32-unknown-unknown/release/mock_oracle.wasm ./res/
@@ -8,6 +8,7 @@ use near_sdk::{
 	PromiseResult,
 	collections::{
 		UnorderedMap,
+		TreeMap,
 	},
 	borsh::{
 		self, 
@@ -15,6 +16,7 @@ use near_sdk::{
 		BorshSerialize
 	}
 };
+use serde::{Deserialize, Serialize};
 
 /** 
  * @title Flux Protocol
@@ -27,6 +29,69 @@ use crate::logger;
 
 /*** Create market type ***/
 type Market = market::Market;
+/*** Create scalar bounds type ***/
+type ScalarBounds = market::ScalarBounds;
+/*** Create resolution source type ***/
+type ResolutionSource = market::ResolutionSource;
+/*** Create protocol config type ***/
+type ProtocolConfig = market::ProtocolConfig;
+type ProtocolConfigView = market::ProtocolConfigView;
+/*** Create payout type ***/
+type Payout = market::Payout;
+/*** Create market listing types ***/
+type MarketFilter = market::MarketFilter;
+type MarketSummary = market::MarketSummary;
+/*** Create stake breakdown type ***/
+type StakeBreakdown = market::StakeBreakdown;
+
+/**
+ * @notice Policy that decides who can create markets
+ * @dev Open = anyone that pays the creation bond, Allowlist = only allowlisted accounts, Approval = markets are proposed and created once the admin approves them
+ *  The admin can always create markets directly
+ */
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum CreationPolicy {
+	Open,
+	Allowlist,
+	Approval,
+}
+
+/**
+ * @notice Validated parameters of a new market together with the creation bond that was escrowed for it
+ * @dev Under the Approval policy it's stored as a proposal and the bond stays escrowed until the proposal is approved, rejected or withdrawn
+ */
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
+pub struct MarketProposal {
+	pub proposer: String,
+	pub description: String,
+	pub extra_info: String,
+	pub outcomes: u64,
+	pub outcome_tags: Vec<String>,
+	pub categories: Vec<String>,
+	pub end_time: u64,
+	pub creator_fee_percentage: u128,
+	pub resolution_fee_percentage: u128,
+	pub affiliate_fee_percentage: u128,
+	pub api_source: String,
+	pub resolution_source: Option<ResolutionSource>,
+	pub dispute_window: u64,
+	pub resolute_bond: u128,
+	pub resolute_bond_percentage: u128,
+	pub designated_reporter: Option<String>,
+	pub oracle: Option<String>,
+	pub scalar_bounds: Option<ScalarBounds>,
+	pub parent: Option<(u64, u64)>,
+	pub bond: u128,
+}
+
+/**
+ * @notice Creates the ordered set of market ids for a single key of a market index
+ * @dev Every key gets its own storage prefix so indexing a market only touches the key's own set instead of rewriting a list of all its markets
+ */
+fn new_market_index(index: &str, key: &String) -> TreeMap<u64, bool> {
+	return TreeMap::new(format!("{}:{}", index, key).as_bytes().to_vec());
+}
 
 /**
  * @notice The state struct for the Flux Protocol implementation 
@@ -34,13 +99,25 @@ type Market = market::Market;
 #[near_bindgen]
 #[derive(BorshDeserialize, BorshSerialize)]
 struct FluxProtocol {
-	owner: String,
+	owner: String, // Can transfer ownership and assign the other roles
+	pending_owner: Option<String>, // Proposed owner that still has to accept ownership
+	admin: String, // Manages the config, creation policy and markets on behalf of the protocol
+	judges: Vec<String>, // Council of judges that votes on the outcome of disputed markets
+	judge_threshold: u64, // Amount of judge votes on the same payout needed to finalize a disputed market
+	pauser: String, // Can pause and unpause the protocol in case of an emergency
 	markets: UnorderedMap<u64, Market>,
 	nonce: u64,
-	max_fee_percentage: u128,
-	creation_bond: u128,
+	config: ProtocolConfig,
 	affiliate_earnings: UnorderedMap<String, u128>,
 	fun_token_account_id: String,
+	conditional_markets: UnorderedMap<u64, Vec<u64>>, // Maps parent market_id => conditional market_ids
+	paused: bool, // Circuit breaker, if true no new markets, orders, resolutions or disputes are accepted
+	markets_by_category: UnorderedMap<String, TreeMap<u64, bool>>, // Maps normalized category => ordered set of market_ids
+	markets_by_creator: UnorderedMap<String, TreeMap<u64, bool>>, // Maps creator account_id => ordered set of market_ids
+	creation_policy: CreationPolicy,
+	creator_allowlist: UnorderedMap<String, bool>, // Accounts that can create markets under the Allowlist policy
+	market_proposals: UnorderedMap<u64, MarketProposal>, // Maps proposal_id => proposal under the Approval policy
+	proposal_nonce: u64,
 }
 
 /**
@@ -49,6 +126,30 @@ struct FluxProtocol {
  */
 const SINGLE_CALL_GAS: u64 = 100000000000000;
 
+/**
+ * @notice The max amount of time a market's end_time can be extended with in one update
+ * @dev Currently set to 30 days in miliseconds
+ */
+const MAX_END_TIME_EXTENSION: u64 = 2592000000;
+
+/**
+ * @notice The max amount of outcomes a market can have
+ * @dev Matching only touches orderbooks with open orders, the cap keeps market creation and claims within gas limits
+ */
+const MAX_OUTCOMES: u64 = 64;
+
+/**
+ * @notice The max amount of conditional markets a market can be the parent of
+ * @dev Conditional markets are invalidated when their parent finalizes, the cap keeps parent finalization within gas limits
+ */
+const MAX_CONDITIONAL_MARKETS: usize = 16;
+
+/**
+ * @notice The max length of a market's extra_info including all clarifications
+ * @dev Clarifications are appended to extra_info, the cap keeps the market's storage bounded
+ */
+const MAX_EXTRA_INFO_LENGTH: usize = 4000;
+
 /*** External Contract Interfaces ***/
 /** @dev To interact with an external contract there needs to be an interface defined in the form of a trait */
 
@@ -64,6 +165,15 @@ pub trait FunToken {
     fn get_balance(&self, owner_id: AccountId) -> u128;
 }
 
+/**
+ * @notice Contract interface for oracle contracts that can report the outcome of a market
+ * @dev get_outcome should panic if the outcome isn't known yet and return None if the market should resolute as invalid
+ */
+#[ext_contract]
+pub trait Oracle {
+	fn get_outcome(&self, market_id: U64) -> Option<U64>;
+}
+
 /**
  * @notice Contract interface for the Flux Protocol contract itself: 
  * @dev: We need to define this interface to be able to call Flux Protocol methods in a promise chain, which is required in NEAR promise API
@@ -72,9 +182,11 @@ pub trait FunToken {
 #[ext_contract]
 pub trait FluxProtocol {
     fn proceed_order_placement(&mut self, sender: String, market_id: u64, outcome: u64, shares: u128, spend: u128, price: u128, affiliate_account_id: Option<String>);
-    fn proceed_market_resolution(&mut self, sender: String, market_id: u64, winning_outcome: Option<u64>, stake: u128);
-	fn proceed_market_dispute(&mut self, sender: String, market_id: u64, winning_outcome: Option<u64>, stake: u128);
-	fn proceed_market_creation(&mut self, sender: String, description: String, extra_info: String, outcomes: u64, outcome_tags: Vec<String>, categories: Vec<String>, end_time: u64, creator_fee_percentage: u128, resolution_fee_percentage: u128, affiliate_fee_percentage: u128, api_source: String);
+    fn proceed_market_resolution(&mut self, sender: String, market_id: u64, payout: Payout, stake: u128);
+	fn proceed_market_dispute(&mut self, sender: String, market_id: u64, payout: Payout, stake: u128);
+	fn proceed_market_proposal(&mut self, proposal: MarketProposal);
+	fn proceed_oracle_resolution(&mut self, market_id: u64);
+	fn proceed_market_creation(&mut self, proposal: MarketProposal);
 }
 
 
@@ -97,7 +209,7 @@ impl FluxProtocol {
 	 * @notice Initialize the Flux Protocol contract
 	 * @dev should be treated as constructor and fired during deployment, the contract is unusable before the init method succeeded
 	 *  panics if the contract is already initialized
-	 * @param owner Account id of the contract owner, the owner is for now set to solve disputes
+	 * @param owner Account id of the contract owner, the owner starts out with the admin and pauser roles and as the only judge
 	 * @param fun_token_account_id The account id of the token used for trading
 	 */
 	#[init]
@@ -107,13 +219,44 @@ impl FluxProtocol {
 	) -> Self {
 		assert!(!env::state_exists(), "Already initialized");
 		Self {
-			owner,
+			owner: owner.to_string(),
+			pending_owner: None,
+			admin: owner.to_string(),
+			judges: vec![owner.to_string()],
+			judge_threshold: 1,
+			pauser: owner,
 			markets: UnorderedMap::new(b"markets".to_vec()),
 			nonce: 0,
-			max_fee_percentage: 500,
-			creation_bond: 25e18 as u128 / 100,
+			config: ProtocolConfig {
+				max_fee_percentage: 500,
+				creation_bond: 25e18 as u128 / 100,
+				resolution_fee_percentage: 100,
+				resolute_bond: 5e18 as u128,
+				min_resolute_bond: 1e18 as u128,
+				max_resolute_bond: 1000e18 as u128,
+				resolute_bond_percentage: 0,
+				max_resolute_bond_percentage: 1000,
+				min_stake: 1e16 as u128,
+				dispute_window: 43200000, // 12 hours
+				min_dispute_window: 3600000, // 1 hour
+				max_dispute_window: 604800000, // 7 days
+				max_dispute_rounds: 1,
+				designated_reporter_window: 86400000, // 24 hours
+				designated_reporter_bond_percentage: 0,
+				designated_reporter_slash_percentage: 5000,
+				resolution_deadline: 2592000000, // 30 days
+				expiry_reward_percentage: 1000,
+			},
 			affiliate_earnings: UnorderedMap::new(b"affiliate_earnings".to_vec()), // This Map is not used for for now, we're adding affiliate fees back in on the next V of the protocol
-			fun_token_account_id
+			fun_token_account_id,
+			conditional_markets: UnorderedMap::new(b"conditional_markets".to_vec()),
+			paused: false,
+			markets_by_category: UnorderedMap::new(b"markets_by_category".to_vec()),
+			markets_by_creator: UnorderedMap::new(b"markets_by_creator".to_vec()),
+			creation_policy: CreationPolicy::Open,
+			creator_allowlist: UnorderedMap::new(b"creator_allowlist".to_vec()),
+			market_proposals: UnorderedMap::new(b"market_proposals".to_vec()),
+			proposal_nonce: 0,
 		}
 	}
 
@@ -128,6 +271,138 @@ impl FluxProtocol {
 		return self.owner.to_string();
 	}
 
+	/**
+	 * @notice Returns the account id that's been proposed as new owner
+	 * @return pending owner's account id if there is one
+	 */
+	pub fn pending_owner(
+		&self
+	) -> Option<String> {
+		return self.pending_owner.clone();
+	}
+
+	/**
+	 * @notice Returns the admin's account id
+	 * @return admin's account id
+	 */
+	pub fn admin(
+		&self
+	) -> String {
+		return self.admin.to_string();
+	}
+
+	/**
+	 * @notice Returns the account ids of the judge council
+	 * @return list of judge account ids
+	 */
+	pub fn judges(
+		&self
+	) -> Vec<String> {
+		return self.judges.to_vec();
+	}
+
+	/**
+	 * @notice Returns the amount of judge votes on the same payout that's needed to finalize a disputed market
+	 * @return the judge threshold
+	 */
+	pub fn judge_threshold(
+		&self
+	) -> U64 {
+		return U64(self.judge_threshold);
+	}
+
+	/**
+	 * @notice Returns the pauser's account id
+	 * @return pauser's account id
+	 */
+	pub fn pauser(
+		&self
+	) -> String {
+		return self.pauser.to_string();
+	}
+
+	/**
+	 * @notice Returns the current protocol config, markets use the config snapshot from their creation
+	 * @return Returns the protocol config where u128 and u64 values are returned as strings
+	 */
+	pub fn get_config(
+		&self
+	) -> ProtocolConfigView {
+		return self.config.to_view();
+	}
+
+	/**
+	 * @notice Returns the current market creation policy
+	 */
+	pub fn get_creation_policy(
+		&self
+	) -> CreationPolicy {
+		return self.creation_policy.clone();
+	}
+
+	/**
+	 * @notice Returns whether an account is on the creator allowlist
+	 */
+	pub fn is_allowlisted_creator(
+		&self,
+		account_id: String
+	) -> bool {
+		return self.creator_allowlist.get(&account_id).unwrap_or(false);
+	}
+
+	/**
+	 * @notice Returns a market proposal that's waiting for approval
+	 * @param proposal_id The id of the proposal
+	 */
+	pub fn get_market_proposal(
+		&self,
+		proposal_id: U64
+	) -> Option<MarketProposal> {
+		let proposal_id: u64 = proposal_id.into();
+		return self.market_proposals.get(&proposal_id);
+	}
+
+	/**
+	 * @notice Returns the structured resolution source of a market so validators and UIs can show how the market resolutes
+	 * @param market_id The id of the market
+	 * @return Returns the market's resolution source, None if the market was created without one
+	 */
+	pub fn get_resolution_source(
+		&self,
+		market_id: U64
+	) -> Option<ResolutionSource> {
+		let market_id: u64 = market_id.into();
+		let market = self.markets.get(&market_id).expect("market doesn't exist");
+		return market.resolution_source;
+	}
+
+	/**
+	 * @notice Returns account_id's resolution and dispute stake per resolution window and what account_id is entitled to
+	 * @dev Unbonded stake can be withdrawn through withdraw_dispute_stake until earnings are claimed, claim_earnings pays out the entitlement
+	 * @param market_id The id of the market
+	 * @param account_id The account to break down the stake for
+	 * @return Returns the stake breakdown
+	 */
+	pub fn get_stake_breakdown(
+		&self,
+		market_id: U64,
+		account_id: String
+	) -> StakeBreakdown {
+		let market_id: u64 = market_id.into();
+		let market = self.markets.get(&market_id).expect("market doesn't exist");
+		return market.get_stake_breakdown(&account_id);
+	}
+
+	/**
+	 * @notice Returns whether the protocol is paused
+	 * @return a bool indicating if the protocol is paused
+	 */
+	pub fn is_paused(
+		&self
+	) -> bool {
+		return self.paused;
+	}
+
 	/**
 	 * @notice Returns the fungible token's account id
 	 * @return Fungible token's account id
@@ -158,6 +433,16 @@ impl FluxProtocol {
 		assert_eq!(self.is_promise_success(), true, "previous promise failed");
 	}
 
+	/**
+	 * @dev Checks if the protocol isn't paused
+	 *  panics if the protocol is paused
+	 */
+	fn assert_not_paused(
+		&self
+	) {
+		assert_eq!(self.paused, false, "protocol is paused");
+	}
+
 	/**
 	 * @notice returns market volume
 	 * @dev only needed for unit tests
@@ -173,6 +458,26 @@ impl FluxProtocol {
 		.filled_volume.into();
 	}
 
+	/**
+	 * @notice Returns the bond that has to be staked on a payout to resolute a market
+	 * @dev Before the first stake this is the market's resolute bond at its current filled volume, after that it's the bond the first stake fixed
+	 *  Validators use this to stake the full bond at once instead of a fixed amount
+	 * @param market_id The id of the market
+	 * @return the bond of the market's resolution round
+	 */
+	pub fn get_resolute_bond(
+		&self,
+		market_id: U64
+	) -> U128 {
+		let market_id: u64 = market_id.into();
+		let market = self.markets.get(&market_id).expect("market doesn't exist");
+		let resolution_window = market.resolution_windows.get(0).expect("no resolution window found, something went wrong");
+		if resolution_window.participants_to_outcome_to_stake.len() == 0 {
+			return market.get_resolute_bond().into();
+		}
+		return resolution_window.required_bond_size.into();
+	}
+
 	/**
 	 * @notice returns market price
 	 * @dev only needed for unit tests
@@ -190,6 +495,89 @@ impl FluxProtocol {
 			.get_market_price(outcome));
 	}
 
+	/**
+	 * @notice Returns a page of market summaries, cancelled markets are left out
+	 * @dev Markets are iterated by id starting at from_index until `limit` matching markets are found or there are no markets left
+	 *  If the filter contains a category or creator only the markets in the corresponding index are iterated, starting at from_index
+	 * @param from_index The market id to start iterating from
+	 * @param limit The max amount of market summaries to return
+	 * @param filter Optional filter on category, creator, state and end_time range
+	 * @return Returns a list of market summaries including the current price of each outcome
+	 */
+	pub fn get_markets(
+		&self,
+		from_index: U64,
+		limit: U64,
+		filter: Option<MarketFilter>
+	) -> Vec<MarketSummary> {
+		let from_index: u64 = from_index.into();
+		let limit: u64 = limit.into();
+		let mut summaries: Vec<MarketSummary> = vec![];
+
+		/* Use the category or creator index if possible to avoid a scan of all markets */
+		let index: Option<TreeMap<u64, bool>> = match &filter {
+			Some(MarketFilter { category: Some(category), .. }) => {
+				let category = market::normalize_category(category);
+				Some(self.markets_by_category.get(&category).unwrap_or(new_market_index("markets_by_category", &category)))
+			},
+			Some(MarketFilter { creator: Some(creator), .. }) => Some(self.markets_by_creator.get(creator).unwrap_or(new_market_index("markets_by_creator", creator))),
+			_ => None
+		};
+		/* `iter_from` skips the given key itself so from_index is checked separately */
+		let market_ids: Box<dyn Iterator<Item = u64> + '_> = match &index {
+			Some(index) => Box::new(
+				Some(from_index).filter(|market_id| index.contains_key(market_id))
+					.into_iter()
+					.chain(index.iter_from(from_index).map(|(market_id, _)| market_id))
+			),
+			None => Box::new(from_index..self.nonce)
+		};
+
+		for market_id in market_ids {
+			if summaries.len() as u64 >= limit {break;}
+
+			let market = self.markets.get(&market_id).expect("market doesn't exist");
+			if market.cancelled {continue;}
+			if let Some(filter) = &filter {
+				if !market.matches_filter(filter) {continue;}
+			}
+
+			summaries.push(market.to_summary());
+		}
+
+		return summaries;
+	}
+
+	/**
+	 * @notice Returns the ids of all markets in a category
+	 * @param category The category to look up, this is normalized so "Sports" and "sports" return the same markets
+	 * @return Returns a list of market ids ordered by creation
+	 */
+	pub fn get_market_ids_by_category(
+		&self,
+		category: String
+	) -> Vec<U64> {
+		return match self.markets_by_category.get(&market::normalize_category(&category)) {
+			Some(market_ids) => market_ids.iter().map(|(market_id, _)| U64(market_id)).collect(),
+			None => vec![]
+		};
+	}
+
+	/**
+	 * @notice Returns the ids of all markets created by an account
+	 * @param creator The account id of the market creator
+	 * @return Returns a list of market ids ordered by creation
+	 */
+	pub fn get_market_ids_by_creator(
+		&self,
+		creator: String
+	) -> Vec<U64> {
+		return match self.markets_by_creator.get(&creator) {
+			Some(market_ids) => market_ids.iter().map(|(market_id, _)| U64(market_id)).collect(),
+			None => vec![]
+		};
+	}
+
 	/**
 	 * @notice returns an account their balance in a certain market for a certain outcome
 	 * @dev only needed for unit tests
@@ -220,12 +608,25 @@ impl FluxProtocol {
 	 * @return Returns a u128 integer representing the creator_fee_percentage denominated in 1e4, meaning 1 == 0.01%
 	 */
 	 fn get_creator_fee_percentage(&self, market: &Market) -> u128 {
-		return match market.winning_outcome {
-			Some(_) => market.creator_fee_percentage,
-			None => 0
+		return match market.is_valid() {
+			true => market.creator_fee_percentage,
+			false => 0
 		}
 	}
 
+	/**
+	 * @notice Returns the market's resolution fee. If nobody resoluted the market there's nobody to pay the fee to so this method returns 0, this includes cancelled markets.
+	 * @dev The same goes for a final oracle report, it isn't backed by stake so nobody shares in the fee
+	 * @param market A reference to the market where from to return the resolution fee
+	 * @return Returns a u128 integer representing the resolution_fee_percentage denominated in 1e4, meaning 1 == 0.01%
+	 */
+	fn get_resolution_fee_percentage(&self, market: &Market) -> u128 {
+		if market.cancelled || market.invalidated_by_parent || market.expired_by.is_some() {return 0}
+		let resolution_window = market.resolution_windows.get(0).expect("no resolution window found, something went wrong");
+		if resolution_window.required_bond_size == 0 && resolution_window.outcome.as_ref() == Some(&market.payout) {return 0}
+		return market.resolution_fee_percentage;
+	}
+
 	/**
 	 * @notice Calculates and returns the amount a user can claim in a market if the current resolution data is correct
 	 * @param market A reference to the market where from to return the creator fee
@@ -245,30 +646,27 @@ impl FluxProtocol {
 			return U128(0);
 		}
 
-		let mut validity_bond = 0;
 		/* If account_id is the market creator, and if the market was resoluted as being valid. If this is the case account_id is eligable to receive the validity bond back */ 
-		if account_id == market.creator && market.validity_bond_claimed == false && market.winning_outcome != None {
-			validity_bond = self.creation_bond;
-		}
+		let validity_bond = market.get_validity_bond_claimable(&account_id);
 
 		/* Get how much would be claimable for account_id, governance earnings relates to wht we call "market governance" or the dispute resolution process */
 		let (winnings, left_in_open_orders, governance_earnings) = market.get_claimable_internal(account_id.to_string());
 		
-		let claimable_if_invalid = match market.winning_outcome {
-			None =>  market.claimable_if_invalid.get(&account_id).unwrap_or(0),
-			_ => 0
+		let claimable_if_invalid = match market.is_valid() {
+			false =>  market.claimable_if_invalid.get(&account_id).unwrap_or(0),
+			true => 0
 		};
 
-		let claimable_if_valid = match market.winning_outcome {
-			Some(_) =>  market.claimable_if_valid.get(&account_id).unwrap_or(0),
-			_ => 0
+		let claimable_if_valid = match market.is_valid() {
+			true =>  market.claimable_if_valid.get(&account_id).unwrap_or(0),
+			false => 0
 		};
 
 		/* Calculate the sum of winnings + claimable_if_invalid to determain what amount of funds can be feed */
 		let total_feeable_amount = winnings + claimable_if_invalid;
 
 		/* Calculate total fee percentage */
-		let total_fee_percentage =  market.resolution_fee_percentage + self.get_creator_fee_percentage(&market);
+		let total_fee_percentage =  self.get_resolution_fee_percentage(&market) + self.get_creator_fee_percentage(&market);
 
 		/* Calculate total fee */
 		let total_fee = (total_feeable_amount * total_fee_percentage + 10000 - 1) / 10000;
@@ -279,6 +677,19 @@ impl FluxProtocol {
 		return U128(to_claim);
 	}
 
+	/**
+	 * @notice Convert the optional payout numerators parameter into an Option<Vec<u128>>
+	 */
+	fn to_payout_numerators(
+		&self,
+		payout_numerators: Option<Vec<U128>>
+	) -> Option<Vec<u128>> {
+		return match payout_numerators {
+			Some(numerators) => Some(numerators.into_iter().map(|numerator| numerator.into()).collect()),
+			None => None
+		};
+	}
+
 	/**
 	 * @dev Panics if the previous promise in the promise chain was unsuccessful
 	 * @return Returns a bool representing the success of the previous promise in a promise chain
@@ -298,30 +709,311 @@ impl FluxProtocol {
 	/*** Setters ***/
 
 	/**
-	 * @notice Change ownership - will be used to change "judge" to either a multisig or a last resort measure
+	 * @notice Proposes a new owner, ownership is only transferred once the new owner accepts it
 	 * @dev Panics if the sender isn't the current owner
+	 * @param new_owner The account id of the proposed owner, None cancels a pending proposal
 	 */
-	pub fn set_owner(
+	pub fn propose_owner(
 		&mut self, 
-		new_owner: String
+		new_owner: Option<String>
 	) {
-		assert_eq!(env::predecessor_account_id(), self.owner, "Owner can only be changed by previous owner");
-		self.owner = new_owner;
+		assert_eq!(env::predecessor_account_id(), self.owner, "only the owner can propose a new owner");
+		self.pending_owner = new_owner;
+		logger::log_role_updated("pending_owner".to_string(), self.pending_owner.clone());
+	}
+
+	/**
+	 * @notice Accepts a pending ownership transfer
+	 * @dev Panics if the sender isn't the pending owner
+	 */
+	pub fn accept_ownership(
+		&mut self
+	) {
+		let sender = env::predecessor_account_id();
+		assert!(Some(sender.to_string()) == self.pending_owner, "only the pending owner can accept ownership");
+		self.owner = sender;
+		self.pending_owner = None;
+		logger::log_role_updated("owner".to_string(), Some(self.owner.to_string()));
+	}
+
+	/**
+	 * @notice Sets the admin, the admin manages the config, creation policy and markets on behalf of the protocol
+	 * @dev Panics if the sender isn't the current owner
+	 * @param admin The account id of the new admin
+	 */
+	pub fn set_admin(
+		&mut self,
+		admin: String
+	) {
+		assert_eq!(env::predecessor_account_id(), self.owner, "only the owner can set the admin");
+		self.admin = admin;
+		logger::log_role_updated("admin".to_string(), Some(self.admin.to_string()));
+	}
+
+	/**
+	 * @notice Sets the judge council, disputed markets are finalized once `threshold` judges voted on the same payout
+	 * @dev Panics if the sender isn't the current owner
+	 *  Panics if the council is empty, has duplicate judges or if the threshold is 0 or higher than the amount of judges
+	 *  Only votes of current judges count towards the threshold, votes are tallied against the current council on the next judge vote
+	 * @param judges The account ids of the judges
+	 * @param threshold The amount of votes on the same payout needed to finalize a disputed market
+	 */
+	pub fn set_judges(
+		&mut self,
+		judges: Vec<String>,
+		threshold: U64
+	) {
+		assert_eq!(env::predecessor_account_id(), self.owner, "only the owner can set the judges");
+		let threshold: u64 = threshold.into();
+		assert!(threshold > 0 && threshold <= judges.len() as u64, "invalid judge threshold");
+		for (i, judge) in judges.iter().enumerate() {
+			assert!(!judges[..i].contains(judge), "duplicate judge");
+		}
+
+		self.judges = judges;
+		self.judge_threshold = threshold;
+		logger::log_judges_updated(&self.judges, self.judge_threshold);
+	}
+
+	/**
+	 * @notice Sets the pauser, an account that can pause and unpause the protocol in case of an emergency
+	 * @dev Panics if the sender isn't the current owner
+	 * @param pauser The account id of the new pauser
+	 */
+	pub fn set_pauser(
+		&mut self,
+		pauser: String
+	) {
+		assert_eq!(env::predecessor_account_id(), self.owner, "only the owner can set the pauser");
+		self.pauser = pauser;
+		logger::log_role_updated("pauser".to_string(), Some(self.pauser.to_string()));
+	}
+
+	/**
+	 * @notice Updates the protocol config, only fields that are provided are updated. Live markets keep using the config snapshot from their creation
+	 * @dev Panics if the sender isn't the admin
+	 *  Panics if the percentages are higher than 100% or if the min stake, min dispute window or max dispute rounds are 0
+	 *  Panics if the default dispute window or resolute bond isn't within its bounds
+	 * @param max_fee_percentage Max creator fee percentage denominated in 1e4 where 100 = 1%
+	 * @param creation_bond Validity bond that's escrowed on market creation
+	 * @param resolution_fee_percentage Fee for resolution participants denominated in 1e4 where 100 = 1%
+	 * @param resolute_bond Default floor of the first resolution round's bond
+	 * @param min_resolute_bond Min resolute bond floor a market creator can choose
+	 * @param max_resolute_bond Max resolute bond floor a market creator can choose
+	 * @param resolute_bond_percentage Default percentage of the filled volume that's added to the resolute bond floor denominated in 1e4 where 100 = 1%
+	 * @param max_resolute_bond_percentage Max resolute bond percentage a market creator can choose
+	 * @param min_stake Minimum stake per resolution or dispute transaction
+	 * @param dispute_window Default duration of a dispute window in ms
+	 * @param min_dispute_window Min dispute window duration in ms a market creator can choose
+	 * @param max_dispute_window Max dispute window duration in ms a market creator can choose
+	 * @param max_dispute_rounds Amount of bonded dispute rounds after which the judge council decides the outcome
+	 * @param designated_reporter_window Duration in ms after a market's end_time in which only its designated reporter can resolute
+	 * @param designated_reporter_bond_percentage Percentage of the resolute bond the designated reporter has to stake denominated in 1e4 where 100 = 1%
+	 * @param designated_reporter_slash_percentage Percentage of the validity bond that is slashed if the designated reporter didn't report in time
+	 * @param resolution_deadline Duration in ms after a market's end_time after which an unresoluted market can be finalized as invalid by anyone
+	 * @param expiry_reward_percentage Percentage of the validity bond that goes to the account that finalizes an expired market
+	 */
+	pub fn set_config(
+		&mut self,
+		max_fee_percentage: Option<U128>,
+		creation_bond: Option<U128>,
+		resolution_fee_percentage: Option<U128>,
+		resolute_bond: Option<U128>,
+		min_resolute_bond: Option<U128>,
+		max_resolute_bond: Option<U128>,
+		resolute_bond_percentage: Option<U128>,
+		max_resolute_bond_percentage: Option<U128>,
+		min_stake: Option<U128>,
+		dispute_window: Option<U64>,
+		min_dispute_window: Option<U64>,
+		max_dispute_window: Option<U64>,
+		max_dispute_rounds: Option<U64>,
+		designated_reporter_window: Option<U64>,
+		designated_reporter_bond_percentage: Option<U128>,
+		designated_reporter_slash_percentage: Option<U128>,
+		resolution_deadline: Option<U64>,
+		expiry_reward_percentage: Option<U128>
+	) {
+		assert_eq!(env::predecessor_account_id(), self.admin, "only the admin can update the config");
+
+		if let Some(max_fee_percentage) = max_fee_percentage {self.config.max_fee_percentage = max_fee_percentage.into();}
+		if let Some(creation_bond) = creation_bond {self.config.creation_bond = creation_bond.into();}
+		if let Some(resolution_fee_percentage) = resolution_fee_percentage {self.config.resolution_fee_percentage = resolution_fee_percentage.into();}
+		if let Some(resolute_bond) = resolute_bond {self.config.resolute_bond = resolute_bond.into();}
+		if let Some(min_resolute_bond) = min_resolute_bond {self.config.min_resolute_bond = min_resolute_bond.into();}
+		if let Some(max_resolute_bond) = max_resolute_bond {self.config.max_resolute_bond = max_resolute_bond.into();}
+		if let Some(resolute_bond_percentage) = resolute_bond_percentage {self.config.resolute_bond_percentage = resolute_bond_percentage.into();}
+		if let Some(max_resolute_bond_percentage) = max_resolute_bond_percentage {self.config.max_resolute_bond_percentage = max_resolute_bond_percentage.into();}
+		if let Some(min_stake) = min_stake {self.config.min_stake = min_stake.into();}
+		if let Some(dispute_window) = dispute_window {self.config.dispute_window = dispute_window.into();}
+		if let Some(min_dispute_window) = min_dispute_window {self.config.min_dispute_window = min_dispute_window.into();}
+		if let Some(max_dispute_window) = max_dispute_window {self.config.max_dispute_window = max_dispute_window.into();}
+		if let Some(max_dispute_rounds) = max_dispute_rounds {self.config.max_dispute_rounds = max_dispute_rounds.into();}
+		if let Some(designated_reporter_window) = designated_reporter_window {self.config.designated_reporter_window = designated_reporter_window.into();}
+		if let Some(designated_reporter_bond_percentage) = designated_reporter_bond_percentage {self.config.designated_reporter_bond_percentage = designated_reporter_bond_percentage.into();}
+		if let Some(designated_reporter_slash_percentage) = designated_reporter_slash_percentage {self.config.designated_reporter_slash_percentage = designated_reporter_slash_percentage.into();}
+		if let Some(resolution_deadline) = resolution_deadline {self.config.resolution_deadline = resolution_deadline.into();}
+		if let Some(expiry_reward_percentage) = expiry_reward_percentage {self.config.expiry_reward_percentage = expiry_reward_percentage.into();}
+
+		assert!(self.config.max_fee_percentage <= 10000, "max_fee_percentage can't be higher than 10000");
+		assert!(self.config.resolution_fee_percentage <= 10000, "resolution_fee_percentage can't be higher than 10000");
+		assert!(self.config.min_stake > 0, "min_stake has to be greater than 0");
+		assert!(self.config.min_resolute_bond >= self.config.min_stake, "min_resolute_bond can't be smaller than min_stake");
+		assert!(self.config.resolute_bond >= self.config.min_resolute_bond && self.config.resolute_bond <= self.config.max_resolute_bond, "resolute_bond has to be within the resolute bond bounds");
+		assert!(self.config.max_resolute_bond_percentage <= 10000, "max_resolute_bond_percentage can't be higher than 10000");
+		assert!(self.config.resolute_bond_percentage <= self.config.max_resolute_bond_percentage, "resolute_bond_percentage can't be higher than max_resolute_bond_percentage");
+		assert!(self.config.min_dispute_window > 0, "min_dispute_window has to be greater than 0");
+		assert!(self.config.dispute_window >= self.config.min_dispute_window && self.config.dispute_window <= self.config.max_dispute_window, "dispute_window has to be within the dispute window bounds");
+		assert!(self.config.max_dispute_rounds > 0, "max_dispute_rounds has to be greater than 0");
+		assert!(self.config.designated_reporter_bond_percentage <= 10000, "designated_reporter_bond_percentage can't be higher than 10000");
+		assert!(self.config.designated_reporter_slash_percentage <= 10000, "designated_reporter_slash_percentage can't be higher than 10000");
+		assert!(self.config.resolution_deadline > self.config.designated_reporter_window, "resolution_deadline has to be greater than the designated reporter window");
+		assert!(self.config.expiry_reward_percentage <= 10000, "expiry_reward_percentage can't be higher than 10000");
+
+		logger::log_config_updated(&self.config);
+	}
+
+	/**
+	 * @notice Sets the market creation policy
+	 * @dev Panics if the sender isn't the admin
+	 *  Proposals that are pending when the policy changes can still be approved or rejected
+	 * @param policy "open", "allowlist" or "approval"
+	 */
+	pub fn set_creation_policy(
+		&mut self,
+		policy: CreationPolicy
+	) {
+		assert_eq!(env::predecessor_account_id(), self.admin, "only the admin can set the creation policy");
+		self.creation_policy = policy;
+	}
+
+	/**
+	 * @notice Adds or removes an account from the creator allowlist
+	 * @dev Panics if the sender isn't the admin
+	 * @param account_id The account to update
+	 * @param allowed Whether the account is allowed to create markets under the Allowlist policy
+	 */
+	pub fn set_allowlisted_creator(
+		&mut self,
+		account_id: String,
+		allowed: bool
+	) {
+		assert_eq!(env::predecessor_account_id(), self.admin, "only the admin can update the creator allowlist");
+		if allowed {
+			self.creator_allowlist.insert(&account_id, &true);
+		} else {
+			self.creator_allowlist.remove(&account_id);
+		}
+	}
+
+	/**
+	 * @notice Pauses market creation, order placement, resolution and disputes. Canceling orders, withdrawing dispute stake and claiming earnings remain available
+	 * @dev Panics if the sender isn't the owner or pauser
+	 *  Panics if the protocol is already paused
+	 * @param reason The reason for pausing the protocol, this is emitted in the pause event
+	 */
+	pub fn pause(
+		&mut self,
+		reason: String
+	) {
+		self.assert_owner_or_pauser();
+		assert_eq!(self.paused, false, "protocol is already paused");
+		self.paused = true;
+		logger::log_protocol_paused(env::predecessor_account_id(), reason);
+	}
+
+	/**
+	 * @notice Unpauses the protocol
+	 * @dev Panics if the sender isn't the owner or pauser
+	 *  Panics if the protocol isn't paused
+	 */
+	pub fn unpause(
+		&mut self
+	) {
+		self.assert_owner_or_pauser();
+		assert_eq!(self.paused, true, "protocol isn't paused");
+		self.paused = false;
+		logger::log_protocol_unpaused(env::predecessor_account_id());
+	}
+
+	/**
+	 * @dev Panics if the sender isn't the owner or pauser
+	 */
+	fn assert_owner_or_pauser(
+		&self
+	) {
+		let sender = env::predecessor_account_id();
+		assert!(sender == self.owner || sender == self.pauser, "only the owner or pauser can pause or unpause the protocol");
 	}
 	
+	/**
+	 * @dev Checks if the market metadata is valid
+	 *  panics if any of the metadata fields is too long or if the outcome_tags don't match the amount of outcomes
+	 */
+	fn assert_valid_metadata(
+		&self,
+		description: &String,
+		extra_info: &String,
+		outcomes: u64,
+		outcome_tags: &Vec<String>,
+		categories: &Vec<String>
+	) {
+		for outcome_tag in outcome_tags {
+			assert!(outcome_tag.chars().count() < 20, "outcome tag can't be more than 20 chars");
+		}
+
+		for category in categories {
+			assert!(category.chars().count() < 20, "category tag can't be more than 20 chars");
+		}
+
+		assert!(description.chars().count() < 201, "description can't than 200 characters");
+		assert!(extra_info.chars().count() < 401, "extra_info can't than 400 characters");
+		assert!(outcomes > 1, "need to have more than 2 outcomes");
+		assert!(outcomes == 2 || outcomes == outcome_tags.len() as u64, "invalid outcomes");
+		assert!(outcomes <= MAX_OUTCOMES, "can't have more than 64 outcomes");
+		assert!(categories.len() < 8, "can't have more than 8 categories");
+
+		if outcomes == 2 {assert!(outcome_tags.len() == 0)}
+	}
+
+	/**
+	 * @notice Normalizes categories so that differently cased categories end up in the same index, duplicates are removed after normalization
+	 */
+	fn normalize_categories(
+		&self,
+		categories: Vec<String>
+	) -> Vec<String> {
+		let mut normalized_categories: Vec<String> = vec![];
+		for category in &categories {
+			let category = market::normalize_category(category);
+			if !normalized_categories.contains(&category) {normalized_categories.push(category);}
+		}
+		return normalized_categories;
+	}
+
 	/**
 	 * @notice Kicks off market creation returns a promise that exists of a promise chain
 	 * @dev Panics if market parameters are invalid
+	 *  Panics if the protocol is paused
+	 *  Panics if the creation policy is Approval and the sender isn't the admin, these markets are proposed through propose_market
 	 *  if outcomes == 2 we assume that it's a binary market and expect outcome_tags to be empty because assume it's ["NO", "YES"]
 	 * @param description A description of the market
 	 * @param extra_info Extra info about the market, these could be specific details like what source should be used to resolve the market etc
-	 * @param outcomes The number out outcomes a market has, min is 2 max is 8
+	 * @param outcomes The number out outcomes a market has, min is 2 max is 64
 	 * @param outcome_tags A list of strings where the outcome id corresponds to the index of the outcome_tags array e.g. outcome 0 = outcome_tags[0]
 	 * @param categories A list of categories that describe the market (helps with filtering)
 	 * @param end_time Unix timestamp in miliseconds of when the market stops being tradeable and can be resoluted
 	 * @param creator_fee_percentage Percentage with two decimals so denominated in 1e4 between 0 - 500 where 1 = 0.01% and 100 = 1%
 	 * @param affiliate_fee_percentage Percentage of the creator fee that should go to affiliate accounts range betwen 1 - 100
 	 * @param api_source For when we have validators running, these validators then use this attribute to automatically resolute / dispute the market
+	 * @param resolution_source Optional structured specification of the endpoint, JSON path, outcome mapping and timestamp the market resolutes on, validated at creation, api_source has to be empty if provided
+	 * @param dispute_window Optional duration of the market's dispute windows in ms, has to be within the protocol's dispute window bounds, defaults to the protocol's dispute window
+	 * @param resolute_bond Optional floor of the resolution round's bond, has to be within the protocol's resolute bond bounds, defaults to the protocol's resolute bond
+	 * @param resolute_bond_percentage Optional percentage of the filled volume at resolution that's added to the resolute bond floor, denominated in 1e4 where 100 = 1%, defaults to the protocol's resolute bond percentage
+	 * @param designated_reporter Optional account that can exclusively resolute the market with a reduced bond for the protocol's designated reporter window after end_time
+	 * @param oracle Optional oracle contract that reports the market's outcome through request_resolution, during the designated reporter window only the oracle can resolute the market
+	 * @param scalar_bounds Optional (min, max) range, if provided the market is a scalar market with outcome 0 = short and outcome 1 = long that resolutes to a value within the range
+	 * @param parent Optional (market_id, outcome) condition, if provided the market is only valid if the parent market finalizes on this outcome
 	 * @return returns a promise chain - this chain tries to escrow the base currency as a validity bond from the market creation and if successful proceed the market creation
 	 * */
 	pub fn create_market(
@@ -334,71 +1026,400 @@ impl FluxProtocol {
 		end_time: U64,
 		creator_fee_percentage: U128,
 		affiliate_fee_percentage: U128,
-		api_source: String
+		api_source: String,
+		resolution_source: Option<ResolutionSource>,
+		dispute_window: Option<U64>,
+		resolute_bond: Option<U128>,
+		resolute_bond_percentage: Option<U128>,
+		designated_reporter: Option<String>,
+		oracle: Option<String>,
+		scalar_bounds: Option<(U64, U64)>,
+		parent: Option<(U64, U64)>
 	) -> Promise {
+		self.assert_not_paused();
+		let sender = env::predecessor_account_id();
+		assert!(self.creation_policy != CreationPolicy::Approval || sender == self.admin, "markets have to be proposed through propose_market under the approval policy");
+		if self.creation_policy == CreationPolicy::Allowlist {
+			assert!(sender == self.admin || self.is_allowlisted_creator(sender.to_string()), "sender isn't allowed to create markets");
+		}
+
+		let proposal = self.new_market_proposal(
+			sender,
+			description,
+			extra_info,
+			outcomes,
+			outcome_tags,
+			categories,
+			end_time,
+			creator_fee_percentage,
+			affiliate_fee_percentage,
+			api_source,
+			resolution_source,
+			dispute_window,
+			resolute_bond,
+			resolute_bond_percentage,
+			designated_reporter,
+			oracle,
+			scalar_bounds,
+			parent
+		);
+
+		/* Promise chain, call external token contract to transfer funds from user to flux protocol contract. Then self call proceed_market_creation. */
+		return fun_token::transfer_from(env::predecessor_account_id(), env::current_account_id(), proposal.bond.into(), &self.fun_token_account_id(), 0, SINGLE_CALL_GAS).then(
+			flux_protocol::proceed_market_creation(
+				proposal,
+				&env::current_account_id(),
+				0,
+				SINGLE_CALL_GAS
+			)
+		);
+	}
+
+	/**
+	 * @notice Proposes a market under the Approval policy, the market is created once the admin approves it
+	 * @dev Panics if the creation policy isn't Approval
+	 *  Panics if the protocol is paused
+	 *  Panics if market parameters are invalid, the parameters are the same as create_market's
+	 * @return returns a promise chain - this chain tries to escrow the base currency as a creation bond and if successful stores the proposal, it resolves to the proposal_id
+	 */
+	pub fn propose_market(
+		&mut self, 
+		description: String, 
+		extra_info: String, 
+		outcomes: U64,
+		outcome_tags: Vec<String>,
+		categories: Vec<String>,
+		end_time: U64,
+		creator_fee_percentage: U128,
+		affiliate_fee_percentage: U128,
+		api_source: String,
+		resolution_source: Option<ResolutionSource>,
+		dispute_window: Option<U64>,
+		resolute_bond: Option<U128>,
+		resolute_bond_percentage: Option<U128>,
+		designated_reporter: Option<String>,
+		oracle: Option<String>,
+		scalar_bounds: Option<(U64, U64)>,
+		parent: Option<(U64, U64)>
+	) -> Promise {
+		self.assert_not_paused();
+		assert!(self.creation_policy == CreationPolicy::Approval, "markets can only be proposed under the approval policy");
+
+		let proposal = self.new_market_proposal(
+			env::predecessor_account_id(),
+			description,
+			extra_info,
+			outcomes,
+			outcome_tags,
+			categories,
+			end_time,
+			creator_fee_percentage,
+			affiliate_fee_percentage,
+			api_source,
+			resolution_source,
+			dispute_window,
+			resolute_bond,
+			resolute_bond_percentage,
+			designated_reporter,
+			oracle,
+			scalar_bounds,
+			parent
+		);
+
+		return fun_token::transfer_from(env::predecessor_account_id(), env::current_account_id(), proposal.bond.into(), &self.fun_token_account_id(), 0, SINGLE_CALL_GAS).then(
+			flux_protocol::proceed_market_proposal(
+				proposal,
+				&env::current_account_id(),
+				0,
+				SINGLE_CALL_GAS
+			)
+		);
+	}
+
+	/**
+	 * @notice Validates the parameters of a new market and converts them into a MarketProposal
+	 * @dev Panics if market parameters are invalid
+	 * @return A MarketProposal with the current creation bond as bond
+	 */
+	fn new_market_proposal(
+		&self,
+		sender: String,
+		description: String, 
+		extra_info: String, 
+		outcomes: U64,
+		outcome_tags: Vec<String>,
+		categories: Vec<String>,
+		end_time: U64,
+		creator_fee_percentage: U128,
+		affiliate_fee_percentage: U128,
+		api_source: String,
+		resolution_source: Option<ResolutionSource>,
+		dispute_window: Option<U64>,
+		resolute_bond: Option<U128>,
+		resolute_bond_percentage: Option<U128>,
+		designated_reporter: Option<String>,
+		oracle: Option<String>,
+		scalar_bounds: Option<(U64, U64)>,
+		parent: Option<(U64, U64)>
+	) -> MarketProposal {
 		let outcomes: u64 = outcomes.into();
 		let end_time: u64 = end_time.into();
 		let creator_fee_percentage: u128 = creator_fee_percentage.into();
 		let affiliate_fee_percentage: u128 = affiliate_fee_percentage.into();
 
-		for outcome_tag in &outcome_tags {
-			assert!(outcome_tag.chars().count() < 20, "outcome tag can't be more than 20 chars");
-		}
+		self.assert_valid_metadata(&description, &extra_info, outcomes, &outcome_tags, &categories);
+		assert!(end_time > env::block_timestamp() / 1000000, "end_time has to be greater than NOW");
+		assert!(creator_fee_percentage <= self.config.max_fee_percentage, "creator_fee_percentage too high");
+		assert!(affiliate_fee_percentage <= 100, "affiliate_fee_percentage can't be higher than 100");
+
+		let dispute_window: u64 = match dispute_window {
+			Some(dispute_window) => dispute_window.into(),
+			None => self.config.dispute_window
+		};
+		assert!(dispute_window >= self.config.min_dispute_window && dispute_window <= self.config.max_dispute_window, "dispute_window has to be within the dispute window bounds");
+
+		let resolute_bond: u128 = match resolute_bond {
+			Some(resolute_bond) => resolute_bond.into(),
+			None => self.config.resolute_bond
+		};
+		let resolute_bond_percentage: u128 = match resolute_bond_percentage {
+			Some(resolute_bond_percentage) => resolute_bond_percentage.into(),
+			None => self.config.resolute_bond_percentage
+		};
+		assert!(resolute_bond >= self.config.min_resolute_bond && resolute_bond <= self.config.max_resolute_bond, "resolute_bond has to be within the resolute bond bounds");
+		assert!(resolute_bond_percentage <= self.config.max_resolute_bond_percentage, "resolute_bond_percentage too high");
+		assert!(designated_reporter.is_none() || oracle.is_none(), "markets can't have both a designated reporter and an oracle");
+		if let Some(reporter) = &designated_reporter {
+			assert!(env::is_valid_account_id(reporter.as_bytes()), "invalid designated reporter account id");
+		}
+		if let Some(oracle) = &oracle {
+			assert!(env::is_valid_account_id(oracle.as_bytes()), "invalid oracle account id");
+		}
+
+		let categories = self.normalize_categories(categories);
+
+		/* Convert the scalar bounds parameter into ScalarBounds */
+		let scalar_bounds: Option<ScalarBounds> = match scalar_bounds {
+			Some((min, max)) => Some(ScalarBounds {
+				min: min.into(), 
+				max: max.into()
+			}),
+			None => None
+		};
+
+		if let Some(bounds) = &scalar_bounds {
+			assert_eq!(outcomes, 2, "scalar markets can only have 2 outcomes (short and long)");
+			assert!(bounds.min < bounds.max, "scalar min has to be smaller than scalar max");
+			assert!(bounds.max < u64::MAX, "scalar max out of range");
+		}
+
+		/* Convert the parent parameter into a (u64, u64) tuple */
+		let parent: Option<(u64, u64)> = match parent {
+			Some((parent_id, parent_outcome)) => Some((parent_id.into(), parent_outcome.into())),
+			None => None
+		};
+
+		if let Some((parent_id, parent_outcome)) = parent {
+			let parent_market = self.markets.get(&parent_id).expect("parent market doesn't exist");
+			assert!(parent_market.scalar_bounds.is_none(), "scalar markets can't be used as parent market");
+			assert!(parent_outcome < parent_market.outcomes, "invalid parent outcome");
+			assert!(parent_market.parent.is_none(), "conditional markets can't be used as parent market");
+			assert_eq!(parent_market.finalized, false, "parent market is already finalized");
+			assert_eq!(parent_market.cancelled, false, "parent market is cancelled");
+			assert!(self.can_add_conditional_market(parent_id), "parent market has reached the max amount of conditional markets");
+		}
+
+		if let Some(source) = &resolution_source {
+			/* Validators only follow the resolution source so an api_source next to it could only contradict it */
+			assert!(api_source.is_empty(), "markets with a resolution_source can't have an api_source");
+			source.assert_valid(outcomes, &scalar_bounds, end_time);
+		}
+
+		let resolution_fee_percentage = self.config.resolution_fee_percentage;
+
+		return MarketProposal {
+			proposer: sender,
+			description,
+			extra_info,
+			outcomes,
+			outcome_tags,
+			categories,
+			end_time,
+			creator_fee_percentage,
+			resolution_fee_percentage,
+			affiliate_fee_percentage,
+			api_source,
+			resolution_source,
+			dispute_window,
+			resolute_bond,
+			resolute_bond_percentage,
+			designated_reporter,
+			oracle,
+			scalar_bounds,
+			parent,
+			bond: self.config.creation_bond,
+		};
+	}
+
+	/**
+	 * @notice Continues market creation
+	 * @dev Panics if the previous promise (token transfer) failed
+	 *  panics if predecessor account_id isn't the Flux Protocol contract itself
+	 *  The proposal carries the creation bond that was escrowed so a config update in between doesn't change the market's validity bond
+	 * @param proposal The validated market parameters and the escrowed creation bond
+	 * @return Returns the newly created market_id
+	 */
+	pub fn proceed_market_creation(
+		&mut self, 
+		proposal: MarketProposal
+	) -> PromiseOrValue<u64> {
+		/* Make sure that the caller of this method is the contract itself */
+		self.assert_self();
+		/* Make sure the previous promise in the promise chain was succesful */
+		self.assert_prev_promise_successful();
+
+		/* The parent might have been finalized or filled up while the creation bond was transferred, if so refund the bond */
+		if let Some((parent_id, _)) = proposal.parent {
+			if !self.can_add_conditional_market(parent_id) {
+				logger::log_market_creation_refunded(proposal.proposer.to_string(), proposal.bond);
+				return PromiseOrValue::Promise(fun_token::transfer(proposal.proposer, U128(proposal.bond), &self.fun_token_account_id(), 0, SINGLE_CALL_GAS));
+			}
+		}
+
+		let market_id = self.create_market_from_proposal(proposal);
+		return PromiseOrValue::Value(market_id);
+	}
+
+	/**
+	 * @notice Stores a market proposal after the creation bond was escrowed
+	 * @dev Panics if the previous promise (token transfer) failed
+	 *  panics if predecessor account_id isn't the Flux Protocol contract itself
+	 * @param proposal The proposed market
+	 * @return Returns the proposal_id
+	 */
+	pub fn proceed_market_proposal(
+		&mut self,
+		proposal: MarketProposal
+	) -> PromiseOrValue<u64> {
+		/* Make sure that the caller of this method is the contract itself */
+		self.assert_self();
+		/* Make sure the previous promise in the promise chain was succesful */
+		self.assert_prev_promise_successful();
 
-		for category in &categories {
-			assert!(category.chars().count() < 20, "category tag can't be more than 20 chars");
+		let proposal_id = self.proposal_nonce;
+		logger::log_market_proposed(proposal_id, &proposal);
+		self.market_proposals.insert(&proposal_id, &proposal);
+		self.proposal_nonce = self.proposal_nonce + 1;
+
+		return PromiseOrValue::Value(proposal_id);
+	}
+
+	/**
+	 * @notice Creates the market of an approved proposal
+	 * @dev Panics if the sender isn't the admin
+	 *  Panics if the protocol is paused
+	 *  Panics if the proposal's end_time has passed or its parent was finalized in the meantime
+	 * @param proposal_id The id of the proposal to approve
+	 * @return Returns the newly created market_id
+	 */
+	pub fn approve_market_proposal(
+		&mut self,
+		proposal_id: U64
+	) -> u64 {
+		self.assert_not_paused();
+		assert_eq!(env::predecessor_account_id(), self.admin, "only the admin can approve market proposals");
+		let proposal_id: u64 = proposal_id.into();
+		let proposal = self.market_proposals.remove(&proposal_id).expect("proposal doesn't exist");
+
+		assert!(proposal.end_time > env::block_timestamp() / 1000000, "end_time has to be greater than NOW");
+		if let Some((parent_id, _)) = proposal.parent {
+			assert!(self.can_add_conditional_market(parent_id), "parent market is already finalized or has reached the max amount of conditional markets");
 		}
 
-		assert!(description.chars().count() < 201, "description can't than 200 characters");
-		assert!(extra_info.chars().count() < 401, "extra_info can't than 400 characters");
-		assert!(outcomes > 1, "need to have more than 2 outcomes");
-		assert!(outcomes == 2 || outcomes == outcome_tags.len() as u64, "invalid outcomes");
-		assert!(outcomes < 8, "can't have more than 8 outcomes"); // up for change
-		assert!(end_time > env::block_timestamp() / 1000000, "end_time has to be greater than NOW");
-		assert!(categories.len() < 8, "can't have more than 8 categories");
-		assert!(creator_fee_percentage <= self.max_fee_percentage, "creator_fee_percentage too high");
-		assert!(affiliate_fee_percentage <= 100, "affiliate_fee_percentage can't be higher than 100");
+		let market_id = self.create_market_from_proposal(proposal);
 
-		if outcomes == 2 {assert!(outcome_tags.len() == 0)}
+		logger::log_market_proposal_approved(proposal_id, market_id);
+		return market_id;
+	}
 
-		/* Promise chain, call external token contract to transfer funds from user to flux protocol contract. Then self call proceed_market_creation. */
-		return fun_token::transfer_from(env::predecessor_account_id(), env::current_account_id(), self.creation_bond.into(), &self.fun_token_account_id(), 0, SINGLE_CALL_GAS).then(
-			flux_protocol::proceed_market_creation(
-				env::predecessor_account_id(), 
-				description,
-				extra_info,
-				outcomes,
-				outcome_tags,
-				categories,
-				end_time,
-				creator_fee_percentage, 
-				100,
-				affiliate_fee_percentage,
-				api_source,
-				&env::current_account_id(),
-				0,
-				SINGLE_CALL_GAS
-			)
+	/**
+	 * @notice Rejects a market proposal and refunds the escrowed creation bond to the proposer
+	 * @dev Panics if the sender isn't the admin
+	 * @param proposal_id The id of the proposal to reject
+	 * @param reason The reason for the rejection, this is emitted in the rejection event
+	 */
+	pub fn reject_market_proposal(
+		&mut self,
+		proposal_id: U64,
+		reason: String
+	) -> Promise {
+		assert_eq!(env::predecessor_account_id(), self.admin, "only the admin can reject market proposals");
+		let proposal_id: u64 = proposal_id.into();
+		let proposal = self.market_proposals.remove(&proposal_id).expect("proposal doesn't exist");
+
+		logger::log_market_proposal_rejected(proposal_id, reason);
+		return fun_token::transfer(proposal.proposer, U128(proposal.bond), &self.fun_token_account_id(), 0, SINGLE_CALL_GAS);
+	}
+
+	/**
+	 * @notice Withdraws a market proposal that hasn't been approved or rejected yet and refunds the escrowed creation bond
+	 * @dev Panics if the sender isn't the proposer
+	 * @param proposal_id The id of the proposal to withdraw
+	 */
+	pub fn withdraw_market_proposal(
+		&mut self,
+		proposal_id: U64
+	) -> Promise {
+		let proposal_id: u64 = proposal_id.into();
+		let proposal = self.market_proposals.get(&proposal_id).expect("proposal doesn't exist");
+		assert_eq!(env::predecessor_account_id(), proposal.proposer, "only the proposer can withdraw the proposal");
+		self.market_proposals.remove(&proposal_id);
+
+		logger::log_market_proposal_withdrawn(proposal_id);
+		return fun_token::transfer(proposal.proposer, U128(proposal.bond), &self.fun_token_account_id(), 0, SINGLE_CALL_GAS);
+	}
+
+	/**
+	 * @notice Creates the market of a proposal whose creation bond has been escrowed
+	 * @dev The market's validity bond is the bond that was escrowed with the proposal
+	 * @return Returns the newly created market_id
+	 */
+	fn create_market_from_proposal(
+		&mut self,
+		proposal: MarketProposal
+	) -> u64 {
+		let mut config = self.config.clone();
+		config.creation_bond = proposal.bond;
+
+		return self.create_market_internal(
+			proposal.proposer, 
+			proposal.description, 
+			proposal.extra_info, 
+			proposal.outcomes, 
+			proposal.outcome_tags, 
+			proposal.categories, 
+			proposal.end_time, 
+			proposal.creator_fee_percentage, 
+			proposal.resolution_fee_percentage, 
+			proposal.affiliate_fee_percentage,
+			proposal.api_source,
+			proposal.resolution_source,
+			proposal.dispute_window,
+			proposal.resolute_bond,
+			proposal.resolute_bond_percentage,
+			proposal.designated_reporter,
+			proposal.oracle,
+			proposal.scalar_bounds,
+			proposal.parent,
+			config
 		);
 	}
 
 	/**
-	 * @notice Continues market creation
-	 * @dev Panics if the previous promise (token transfer) failed
-	 *  panics if predecessor account_id isn't the Flux Protocol contract itself
-	 * @param sender The account_id that signed the create_market transaction
-	 * @param description A description of the market
-	 * @param extra_info Extra info about the market, these could be specific details like what source should be used to resolve the market etc
-	 * @param outcomes The number out outcomes a market has, min is 2 max is 8
-	 * @param outcome_tags A list of strings where the outcome id corresponds to the index of the outcome_tags array e.g. outcome 0 = outcome_tags[0]
-	 * @param categories A list of categories that describe the market (helps with filtering)
-	 * @param end_time Unix timestamp in miliseconds of when the market stops being tradeable and can be resoluted
-	 * @param creator_fee_percentage Percentage with two decimals so denominated in 1e4 between 0 - 500 where 1 = 0.01% and 100 = 1%
-	 * @param affiliate_fee_percentage Percentage of the creator fee that should go to affiliate accounts range betwen 1 - 100
-	 * @param api_source For when we have validators running, these validators then use this attribute to automatically resolute / dispute the market
+	 * @notice Creates and stores a new market, the creation bond has to be escrowed before this is called
 	 * @return Returns the newly created market_id
 	 */
-	pub fn proceed_market_creation(
+	fn create_market_internal(
 		&mut self, 
 		sender: String, 
 		description: String, 
@@ -410,13 +1431,17 @@ impl FluxProtocol {
 		creator_fee_percentage: u128, 
 		resolution_fee_percentage: u128, 
 		affiliate_fee_percentage: u128, 
-		api_source: String
-	) -> PromiseOrValue<u64> {
-		/* Make sure that the caller of this method is the contract itself */
-		self.assert_self();
-		/* Make sure the previous promise in the promise chain was succesful */
-		self.assert_prev_promise_successful();
-
+		api_source: String,
+		resolution_source: Option<ResolutionSource>,
+		dispute_window: u64,
+		resolute_bond: u128,
+		resolute_bond_percentage: u128,
+		designated_reporter: Option<String>,
+		oracle: Option<String>,
+		scalar_bounds: Option<ScalarBounds>,
+		parent: Option<(u64, u64)>,
+		config: ProtocolConfig
+	) -> u64 {
 		/* Create new market instance */
 		let new_market = Market::new(
 			self.nonce, 
@@ -430,7 +1455,16 @@ impl FluxProtocol {
 			creator_fee_percentage, 
 			resolution_fee_percentage, 
 			affiliate_fee_percentage,
-			api_source
+			api_source,
+			resolution_source,
+			dispute_window,
+			resolute_bond,
+			resolute_bond_percentage,
+			designated_reporter,
+			oracle,
+			scalar_bounds,
+			parent,
+			config
 		);
 		
 		/* Get the newly created market's resolution_window */
@@ -440,6 +1474,16 @@ impl FluxProtocol {
 		logger::log_new_resolution_window(new_market.id, resolution_window.round, resolution_window.required_bond_size, resolution_window.end_time);
 
 		let market_id = new_market.id;
+
+		/* If the market is a conditional market register it under its parent so it can be invalidated once the parent finalizes */
+		if let Some((parent_id, _)) = new_market.parent {
+			let mut conditional_markets = self.conditional_markets.get(&parent_id).unwrap_or(vec![]);
+			conditional_markets.push(market_id);
+			self.conditional_markets.insert(&parent_id, &conditional_markets);
+		}
+
+		/* Add the market to the category and creator indexes */
+		self.index_market(&new_market);
 		
 		/* Re-insert the markets into the markets map with the market_id as key */
 		self.markets.insert(&self.nonce, &new_market);
@@ -447,12 +1491,13 @@ impl FluxProtocol {
 		/* Increment nonce, for next market's id */
 		self.nonce = self.nonce + 1;
 
-		return PromiseOrValue::Value(market_id);
+		return market_id;
 	}
 
 	/** 
 	 * @notice Kicks off order placement
 	 * @dev Panics if the order parameters are invalid
+	 *  Panics if the protocol is paused
 	 * @param market_id The id of the market
 	 * @param outcome The specific outcome this order wants to buy
 	 * @param shares The amount of shares a user wants to buy denominated in 1e16
@@ -468,6 +1513,7 @@ impl FluxProtocol {
 		price: U128,
 		affiliate_account_id: Option<String>
 	) -> Promise {
+		self.assert_not_paused();
 		let market_id: u64 = market_id.into();
 		let outcome: u64 = outcome.into();
 		let price: u128 = price.into();
@@ -480,6 +1526,9 @@ impl FluxProtocol {
 		assert!(outcome < market.outcomes, "invalid outcome");
 		assert_eq!(market.resoluted, false, "market has already been resoluted");
 		assert!(env::block_timestamp() / 1000000 < market.end_time, "market has already ended");
+		assert_eq!(market.invalidated_by_parent, false, "market was invalidated by its parent market");
+		assert_eq!(market.paused, false, "market is paused");
+		assert_eq!(market.cancelled, false, "market is cancelled");
 
 		/* Attempt to transfer deposit the tokens from the user to this contract, then continue order placement */
 		return fun_token::transfer_from(env::predecessor_account_id(), env::current_account_id(), rounded_spend.into(), &self.fun_token_account_id(), 0, SINGLE_CALL_GAS / 10)
@@ -503,6 +1552,8 @@ impl FluxProtocol {
 	 * @notice Kicks off order placement
 	 * @dev Panics if the signer isn't the contract itself
 	 *  panics if the previous promise wasn't successful due to lack of balance or allowance
+	 *  If the market or protocol was paused while the order was in flight the order is rejected and spend is refunded, a resting unmatched order could cross the book
+	 *  The same goes for markets that were cancelled or invalidated by their parent in the meantime, fills on a dead market would be charged fees nobody can earn
 	 * @param sender The signer of the original place_order transaction
 	 * @param market_id The id of the market
 	 * @param outcome The specific outcome this order wants to buy
@@ -528,6 +1579,11 @@ impl FluxProtocol {
 		self.assert_prev_promise_successful();
 		
 		let mut market = self.markets.get(&market_id).expect("market doesn't exist");
+		if market.paused || self.paused || market.cancelled || market.invalidated_by_parent {
+			logger::log_order_refunded(market_id, sender.to_string(), outcome, spend);
+			return PromiseOrValue::Promise(fun_token::transfer(sender, U128(spend), &self.fun_token_account_id(), 0, SINGLE_CALL_GAS));
+		}
+
 		market.place_order_internal(sender, outcome, shares, spend, price, affiliate_account_id);
 		self.markets.insert(&market.id, &market);
 		return PromiseOrValue::Value(true);
@@ -539,6 +1595,7 @@ impl FluxProtocol {
 	 *  panics if the min_price > 99
 	 *  panics if shares < 1
 	 *  panics if the market is already finalized
+	 *  panics if the market or protocol is paused
 	 *  panics if there are no shares to sell owned by the sender for the min_price
 	 * @param market_id The id of the market to sell shares
 	 * @param outcome The specific outcome this order wants to sell shares
@@ -552,6 +1609,7 @@ impl FluxProtocol {
 		shares: U128,
 		min_price: U128
 	) {
+		self.assert_not_paused();
 		let market_id: u64 = market_id.into();
 		let outcome: u64 = outcome.into();
 		let shares: u128 = shares.into();
@@ -563,6 +1621,7 @@ impl FluxProtocol {
 		
 		let mut market = self.markets.get(&market_id).expect("non existent market");
 		assert_eq!(market.finalized, false, "can't sell shares after market is finalized");
+		assert_eq!(market.paused, false, "market is paused");
 		let earnings = market.dynamic_market_sell_internal(outcome, shares, min_price);
 		assert!(earnings > 0, "no matching orders");
 		self.markets.insert(&market_id, &market);
@@ -570,6 +1629,190 @@ impl FluxProtocol {
 		fun_token::transfer(env::predecessor_account_id(), U128(earnings), &self.fun_token_account_id(), 0, SINGLE_CALL_GAS);
 	}
 
+	/**
+	 * @notice Halts trading on a market, open orders can still be canceled while a market is paused
+	 * @dev Panics if the sender isn't the admin or the market creator
+	 *  Panics if the market is already paused
+	 *  Panics if the market is already resoluted
+	 * @param market_id The id of the market to pause
+	 */
+	pub fn pause_market(
+		&mut self,
+		market_id: U64
+	) {
+		let market_id: u64 = market_id.into();
+		let mut market = self.markets.get(&market_id).expect("market doesn't exist");
+		let sender = env::predecessor_account_id();
+		assert!(sender == self.admin || sender == market.creator, "only the admin or market creator can pause a market");
+		assert_eq!(market.paused, false, "market is already paused");
+		assert_eq!(market.resoluted, false, "market has already been resoluted");
+
+		market.paused = true;
+		self.markets.insert(&market_id, &market);
+
+		logger::log_market_paused(market_id, sender);
+	}
+
+	/**
+	 * @notice Resumes trading on a paused market
+	 * @dev Panics if the sender isn't the admin or the market creator
+	 *  Panics if the market isn't paused
+	 * @param market_id The id of the market to resume
+	 */
+	pub fn resume_market(
+		&mut self,
+		market_id: U64
+	) {
+		let market_id: u64 = market_id.into();
+		let mut market = self.markets.get(&market_id).expect("market doesn't exist");
+		let sender = env::predecessor_account_id();
+		assert!(sender == self.admin || sender == market.creator, "only the admin or market creator can resume a market");
+		assert_eq!(market.paused, true, "market isn't paused");
+
+		market.paused = false;
+		self.markets.insert(&market_id, &market);
+
+		logger::log_market_resumed(market_id, sender);
+	}
+
+	/**
+	 * @notice Corrects the metadata of a market that hasn't been traded yet
+	 * @dev Panics if the sender isn't the market creator
+	 *  Panics if any of the market's orders have been filled
+	 *  Panics if there are open orders on the market, traders placed those based on the current metadata
+	 *  Panics if the market is cancelled or resoluted
+	 *  Panics if the new metadata is invalid, the stored extra_info is only validated if it's replaced because clarifications can take it past 400 characters
+	 * @param market_id The id of the market to update
+	 * @param description Optional new description
+	 * @param extra_info Optional new extra_info
+	 * @param outcome_tags Optional new outcome_tags, needs to match the amount of outcomes of the market
+	 * @param categories Optional new categories
+	 */
+	pub fn update_market_metadata(
+		&mut self,
+		market_id: U64,
+		description: Option<String>,
+		extra_info: Option<String>,
+		outcome_tags: Option<Vec<String>>,
+		categories: Option<Vec<String>>
+	) {
+		let market_id: u64 = market_id.into();
+		let mut market = self.markets.get(&market_id).expect("market doesn't exist");
+		assert_eq!(env::predecessor_account_id(), market.creator, "only the market creator can update the market's metadata");
+		assert_eq!(market.filled_volume, 0, "market has already been traded, only clarifications can be added");
+		assert!(!market.has_open_orders(), "market has open orders, only clarifications can be added");
+		assert_eq!(market.cancelled, false, "market is cancelled");
+		assert_eq!(market.resoluted, false, "market has already been resoluted");
+
+		let description = description.unwrap_or(market.description.to_string());
+		let outcome_tags = outcome_tags.unwrap_or(market.outcome_tags.to_vec());
+		let categories = categories.unwrap_or(market.categories.to_vec());
+		/* Appended clarifications don't count towards the extra_info limit, so an unchanged extra_info is left out of the validation */
+		self.assert_valid_metadata(&description, extra_info.as_ref().unwrap_or(&String::new()), market.outcomes, &outcome_tags, &categories);
+		let extra_info = extra_info.unwrap_or(market.extra_info.to_string());
+		let categories = self.normalize_categories(categories);
+
+		/* Categories might change so the market is re-indexed */
+		self.unindex_market(&market);
+		market.update_metadata_internal(description, extra_info, outcome_tags, categories);
+		self.index_market(&market);
+
+		self.markets.insert(&market_id, &market);
+	}
+
+	/**
+	 * @notice Appends a timestamped clarification to a market's extra_info, this is possible after the market has been traded
+	 * @dev Panics if the sender isn't the market creator
+	 *  Panics if the market is cancelled or finalized
+	 *  Panics if the clarification is empty or longer than 400 characters
+	 *  Panics if the clarification would make extra_info longer than MAX_EXTRA_INFO_LENGTH characters
+	 * @param market_id The id of the market to clarify
+	 * @param clarification The clarification to append
+	 */
+	pub fn add_market_clarification(
+		&mut self,
+		market_id: U64,
+		clarification: String
+	) {
+		let market_id: u64 = market_id.into();
+		let mut market = self.markets.get(&market_id).expect("market doesn't exist");
+		assert_eq!(env::predecessor_account_id(), market.creator, "only the market creator can add clarifications");
+		assert_eq!(market.cancelled, false, "market is cancelled");
+		assert_eq!(market.finalized, false, "market is already finalized");
+		assert!(clarification.chars().count() > 0, "clarification can't be empty");
+		assert!(clarification.chars().count() < 401, "clarification can't than 400 characters");
+		assert!(market.extra_info.chars().count() + clarification.chars().count() < MAX_EXTRA_INFO_LENGTH, "market has reached the max extra_info length");
+
+		market.add_clarification_internal(clarification);
+		self.markets.insert(&market_id, &market);
+	}
+
+	/**
+	 * @notice Cancels a market that hasn't been traded yet and returns the validity bond to the creator
+	 * @dev Panics if the sender isn't the admin or the market creator
+	 *  Open orders aren't refunded in this call so a market with many open orders can always be cancelled,
+	 *  order owners pull their refund through cancel_order or claim_earnings
+	 *  Panics if any of the market's orders have been filled
+	 *  Panics if the market is already cancelled or resoluted
+	 *  Conditional markets of the cancelled market are invalidated
+	 * @param market_id The id of the market to cancel
+	 */
+	pub fn cancel_market(
+		&mut self,
+		market_id: U64
+	) {
+		let market_id: u64 = market_id.into();
+		let mut market = self.markets.get(&market_id).expect("market doesn't exist");
+		let sender = env::predecessor_account_id();
+		assert!(sender == self.admin || sender == market.creator, "only the admin or market creator can cancel a market");
+		assert_eq!(market.filled_volume, 0, "market has already been traded");
+		assert_eq!(market.cancelled, false, "market is already cancelled");
+		assert_eq!(market.resoluted, false, "market has already been resoluted");
+
+		market.cancel_internal();
+
+		self.markets.insert(&market_id, &market);
+		self.unindex_market(&market);
+		self.invalidate_conditional_markets(&market);
+
+		fun_token::transfer(market.creator.to_string(), U128(market.config.creation_bond), &self.fun_token_account_id(), 0, SINGLE_CALL_GAS);
+	}
+
+	/**
+	 * @notice Closes trading early or extends the end_time of a market, e.g. when an event happens early or gets postponed
+	 * @dev Panics if the sender isn't the admin or the market creator
+	 *  Panics if the market is already resoluted or resolution has already started
+	 *  Panics if the market has already ended, a closed market can't be reopened for trading
+	 *  Panics if the new end_time is in the past
+	 *  Panics if the new end_time is more than MAX_END_TIME_EXTENSION after the end_time the market was created with
+	 *  Panics if the new end_time is after the timestamp of the market's resolution source
+	 * @param market_id The id of the market to update
+	 * @param end_time Unix timestamp in miliseconds of when the market stops being tradeable and can be resoluted
+	 */
+	pub fn update_market_end_time(
+		&mut self,
+		market_id: U64,
+		end_time: U64
+	) {
+		let market_id: u64 = market_id.into();
+		let end_time: u64 = end_time.into();
+		let mut market = self.markets.get(&market_id).expect("market doesn't exist");
+		let sender = env::predecessor_account_id();
+		assert!(sender == self.admin || sender == market.creator, "only the admin or market creator can update the end_time");
+		assert_eq!(market.resoluted, false, "market has already been resoluted");
+		assert!(env::block_timestamp() / 1000000 < market.end_time, "market has already ended");
+		assert!(end_time >= env::block_timestamp() / 1000000, "end_time can't be in the past");
+		/* Extensions are capped relative to the original end_time so repeated updates can't postpone the market indefinitely */
+		assert!(end_time <= market.original_end_time + MAX_END_TIME_EXTENSION, "end_time can't be extended by more than 30 days");
+
+		if let Some(source) = &market.resolution_source {
+			assert!(end_time <= source.timestamp, "end_time can't be after the resolution source timestamp");
+		}
+
+		market.update_end_time_internal(sender, end_time);
+		self.markets.insert(&market_id, &market);
+	}
+
 	/**
 	 * @notice Cancels an order and returns outstanding open value to order creator
 	 * @dev Panics if the predecessor_account isn't the owner of the order he's trying to cancel
@@ -603,7 +1846,7 @@ impl FluxProtocol {
 		let to_return = orderbook.cancel_order(order);
 		
 		/* Reinsert the orderbook and market to update state */
-		market.orderbooks.insert(&outcome, &orderbook);
+		market.insert_orderbook(outcome, &orderbook);
 		self.markets.insert(&market_id, &market);
 
 		/* Transfer value left in open order to order owner */
@@ -613,21 +1856,28 @@ impl FluxProtocol {
 	/**
 	 * @notice Kicks off market resolution, supply the outcome data to the 
 	 * @dev Panics if the market hasn't ended yet
+	 *  Panics if the protocol is paused
 	 *  Panics if the market doens't exist
 	 *  Panics if the market is already resoluted
 	 *  Panics if the market is already finalized
 	 *  Panics if the winning_outcome is invalid
 	 *  Panics if the user doesn't have enough balance / allowance to transfer `stake`
+	 *  Panics if the market is in its designated reporting window and the sender isn't the designated reporter or doesn't stake the full reporter bond
+	 *  Panics if the market has an oracle and is in its reporting window, oracle markets are resoluted through request_resolution
+	 *  Panics if the market's resolution deadline has passed, the market can only be finalized as invalid at that point
 	 * @param market_id The id of the market to resolute
-	 * @param winning_outcome The winning_outcome according to the staker
+	 * @param winning_outcome The winning_outcome according to the staker, for scalar markets this is the resolution value
 	 * @param stake The amount of stake the user wants to contribute to the resolution round
+	 * @param payout_numerators Optional payout numerator per outcome for split resolutions, can't be combined with winning_outcome
 	 */
 	pub fn resolute_market(
 		&mut self, 
 		market_id: U64, 
 		winning_outcome: Option<U64>,
-		stake: U128
+		stake: U128,
+		payout_numerators: Option<Vec<U128>>
 	) -> Promise {
+		self.assert_not_paused();
 		let market_id: u64 = market_id.into();
 		let winning_outcome: Option<u64> = match winning_outcome {
 			Some(outcome) => Some(outcome.into()),
@@ -635,11 +1885,22 @@ impl FluxProtocol {
 		};
 		let stake_u128: u128 = stake.into();
 		let market = self.markets.get(&market_id).expect("market doesn't exist");
-		assert!(stake_u128 >= 1e16 as u128, "stake needs to greater than 1e16");
+		assert!(stake_u128 >= market.config.min_stake, "stake needs to be greater than the min stake");
 		assert!(env::block_timestamp() / 1000000 >= market.end_time, "market hasn't ended yet");
 		assert_eq!(market.resoluted, false, "market is already resoluted");
 		assert_eq!(market.finalized, false, "market is already finalized");
-		assert!(winning_outcome == None || winning_outcome.unwrap() < market.outcomes, "invalid winning outcome");
+		assert_eq!(market.cancelled, false, "market is cancelled");
+		assert!(!market.is_past_resolution_deadline(), "resolution deadline has passed, market can only be finalized as invalid");
+		if let Some((parent_id, _)) = market.parent {
+			let parent_market = self.markets.get(&parent_id).expect("parent market doesn't exist");
+			assert_eq!(parent_market.finalized, true, "parent market isn't finalized yet");
+		}
+		if market.in_designated_reporting_window() {
+			assert!(market.oracle.is_none(), "oracle markets are resoluted through request_resolution during the reporting window");
+			assert!(market.designated_reporter == Some(env::predecessor_account_id()), "only the designated reporter can resolute during the reporting window");
+			assert!(stake_u128 >= market.get_designated_reporter_bond(), "designated reporter has to stake the full reporter bond");
+		}
+		let payout = market.to_payout(winning_outcome, self.to_payout_numerators(payout_numerators));
 
 		/* Transfer from sender to contract then proceed resolution */
 		return fun_token::transfer_from(env::predecessor_account_id(), env::current_account_id(), stake, &self.fun_token_account_id(), 0, SINGLE_CALL_GAS / 2)
@@ -647,7 +1908,7 @@ impl FluxProtocol {
 			flux_protocol::proceed_market_resolution(
 				env::predecessor_account_id(),
 				market_id,
-				winning_outcome,
+				payout,
 				stake_u128,
 				&env::current_account_id(),
 				0,
@@ -661,14 +1922,14 @@ impl FluxProtocol {
 	 * @dev Panics if the previous method (transfer) failed
 	 *  Panics if the predecessor_id isn't equal to the contract id itself
 	 * @param market_id The id of the market to resolute
-	 * @param winning_outcome The winning_outcome according to the staker
+	 * @param payout The payout according to the staker
 	 * @param stake The amount of stake the user wants to contribute to the resolution round
 	 * @param sender The account id of the original transaction's signer
 	 */
 	pub fn proceed_market_resolution(
 		&mut self,
 		market_id: u64,
-		winning_outcome: Option<u64>,
+		payout: Payout,
 		stake: u128,
 		sender: String
 	) -> PromiseOrValue<bool> {
@@ -680,7 +1941,7 @@ impl FluxProtocol {
 		let mut market = self.markets.get(&market_id).unwrap();
 		
 		/* Resolute the market, which returns how much of the stake the sender overpaid */
-		let change: u128 = market.resolute_internal(sender.to_string(), winning_outcome, stake).into();
+		let change: u128 = market.resolute_internal(sender.to_string(), payout, stake).into();
 		self.markets.insert(&market_id, &market);
 
 		/* If the sender overstaked return amount to the sender  */
@@ -692,25 +1953,112 @@ impl FluxProtocol {
 		}
 	}
 
+	/**
+	 * @notice Requests the outcome of a market from its oracle, anyone can call this once the market has ended
+	 * @dev Panics if the protocol is paused
+	 *  Panics if the market doesn't have an oracle
+	 *  Panics if the market hasn't ended yet, is already resoluted, finalized or cancelled
+	 *  Panics if the market's resolution deadline has passed
+	 *  Panics if someone already staked on the market's resolution
+	 * @param market_id The id of the market to request the outcome for
+	 * @return returns a promise chain that fetches the outcome from the oracle and resolutes the market with it
+	 */
+	pub fn request_resolution(
+		&mut self,
+		market_id: U64
+	) -> Promise {
+		self.assert_not_paused();
+		let market_id: u64 = market_id.into();
+		let market = self.markets.get(&market_id).expect("market doesn't exist");
+		let oracle = market.oracle.to_owned().expect("market doesn't have an oracle");
+		assert!(env::block_timestamp() / 1000000 >= market.end_time, "market hasn't ended yet");
+		assert_eq!(market.resoluted, false, "market is already resoluted");
+		assert_eq!(market.finalized, false, "market is already finalized");
+		assert_eq!(market.cancelled, false, "market is cancelled");
+		assert!(!market.is_past_resolution_deadline(), "resolution deadline has passed, market can only be finalized as invalid");
+		let resolution_window = market.resolution_windows.get(0).expect("no resolution window found, something went wrong");
+		assert_eq!(resolution_window.participants_to_outcome_to_stake.len(), 0, "market already has resolution stake");
+
+		logger::log_resolution_requested(market_id, env::predecessor_account_id(), oracle.to_string());
+
+		/* Call the oracle then proceed resolution with the reported outcome */
+		return oracle::get_outcome(U64(market_id), &oracle, 0, SINGLE_CALL_GAS / 2).then(
+			flux_protocol::proceed_oracle_resolution(
+				market_id,
+				&env::current_account_id(),
+				0,
+				SINGLE_CALL_GAS
+			)
+		);
+	}
+
+	/**
+	 * @notice Resolutes a market with the outcome its oracle reported, the oracle's report opens a dispute window like any other resolution
+	 * @dev Panics if the oracle call failed, which is the case if the oracle doesn't know the outcome yet
+	 *  panics if predecessor account_id isn't the Flux Protocol contract itself
+	 *  Panics if the protocol was paused or the market was resoluted, finalized or cancelled while waiting on the oracle
+	 * @param market_id The id of the market to resolute
+	 */
+	pub fn proceed_oracle_resolution(
+		&mut self,
+		market_id: u64
+	) -> bool {
+		/* Make sure that the caller of this method is the contract itself */
+		self.assert_self();
+		assert_eq!(env::promise_results_count(), 1, "Contract expected a result on the callback");
+
+		let winning_outcome: Option<U64> = match env::promise_result(0) {
+			PromiseResult::Successful(result) => serde_json::from_slice(&result).expect("invalid oracle response"),
+			_ => panic!("oracle didn't report an outcome")
+		};
+
+		/* The protocol or market state could have changed while waiting on the oracle */
+		self.assert_not_paused();
+		let mut market = self.markets.get(&market_id).expect("market doesn't exist");
+		assert_eq!(market.resoluted, false, "market is already resoluted");
+		assert_eq!(market.finalized, false, "market is already finalized");
+		assert_eq!(market.cancelled, false, "market is cancelled");
+		assert!(!market.is_past_resolution_deadline(), "resolution deadline has passed, market can only be finalized as invalid");
+		let resolution_window = market.resolution_windows.get(0).expect("no resolution window found, something went wrong");
+		assert_eq!(resolution_window.participants_to_outcome_to_stake.len(), 0, "market already has resolution stake");
+
+		let winning_outcome: Option<u64> = match winning_outcome {
+			Some(outcome) => Some(outcome.into()),
+			None => None
+		};
+		let payout = market.to_payout(winning_outcome, None);
+		let oracle = market.oracle.to_owned().expect("market doesn't have an oracle");
+
+		/* The oracle's report bonds the resolution round without stake */
+		market.resolute_internal(oracle, payout, 0);
+		self.markets.insert(&market_id, &market);
+
+		return true;
+	}
+
 	/**
 	 * @notice Kicks of a dispute of a certain outcome
 	 * @dev Panics if the market hasn't been resoluted yet
+	 *  Panics if the protocol is paused
 	 *  Panics if the market doens't exist
 	 *  Panics if the market is already finalized
 	 *  Panics if the winning_outcome is invalid
 	 *  Panics if the disputed outcomeis the same outcome as the previous winning outcome
 	 *  Panics if the sender doesn't have enough balance / allowance to transfer `stake`
-	 *  Panics if the dispute round is > 1. After one initial dispute the market has to be finalized by the owner ("judge")
+	 *  Panics if the market's max dispute rounds have been bonded, after that the market has to be finalized by the judge council
 	 * @param market_id The id of the market to dispute
 	 * @param winning_outcome The winning_outcome according to the staker
 	 * @param stake The amount of stake the sender wants to contribute to the dispute round
+	 * @param payout_numerators Optional payout numerator per outcome for split resolutions, can't be combined with winning_outcome
 	 */
 	pub fn dispute_market(
 		&mut self, 
 		market_id: U64, 
 		winning_outcome: Option<U64>,
-		stake: U128
+		stake: U128,
+		payout_numerators: Option<Vec<U128>>
 	) -> Promise {
+		self.assert_not_paused();
 		let market_id: u64 = market_id.into();
 		let winning_outcome: Option<u64> = match winning_outcome {
 			Some(outcome) => Some(outcome.into()),
@@ -719,13 +2067,13 @@ impl FluxProtocol {
 		let stake_u128: u128 = stake.into();
         let market = self.markets.get(&market_id).expect("market doesn't exist");
 		
-		assert!(stake_u128 >= 1e16 as u128, "stake needs to greater than 1e16");
+		assert!(stake_u128 >= market.config.min_stake, "stake needs to be greater than the min stake");
 		assert_eq!(market.resoluted, true, "market isn't resoluted yet");
 		assert_eq!(market.finalized, false, "market is already finalized");
-        assert!(winning_outcome == None || winning_outcome.unwrap() < market.outcomes, "invalid winning outcome");
-        assert!(winning_outcome != market.winning_outcome, "same oucome as last resolution");
+		let payout = market.to_payout(winning_outcome, self.to_payout_numerators(payout_numerators));
+        assert!(payout != market.payout, "same oucome as last resolution");
+		assert_eq!(market.disputed, false, "dispute rounds exhausted, market has to be finalized by the judge council");
 		let resolution_window = market.resolution_windows.get(market.resolution_windows.len() - 1).expect("Invalid dispute window unwrap");
-		assert_eq!(resolution_window.round, 1, "for this version, there's only 1 round of dispute");
 		assert!(env::block_timestamp() / 1000000 < resolution_window.end_time, "dispute window is closed, market can be finalized");
 
 		/* Transfer from sender to contract then proceed dispute */
@@ -733,7 +2081,7 @@ impl FluxProtocol {
 			flux_protocol::proceed_market_dispute(
 				env::predecessor_account_id(),
 				market_id,
-				winning_outcome,
+				payout,
 				stake_u128,
 				&env::current_account_id(), 
 				0, 
@@ -748,14 +2096,14 @@ impl FluxProtocol {
 	 * @dev Panics if the previous method (transfer) failed
 	 *  Panics if the predecessor_id isn't equal to the contract id itself
 	 * @param market_id The id of the market to dispute
-	 * @param winning_outcome The winning_outcome according to the staker
+	 * @param payout The payout according to the staker
 	 * @param stake The amount of stake the sender wants to contribute to the dispute round
 	 * @param sender The account id of the original transaction's signer
 	 */
 	pub fn proceed_market_dispute(		
 		&mut self,
 		market_id: u64,
-		winning_outcome: Option<u64>,
+		payout: Payout,
 		stake: u128,
 		sender: String
 	) -> PromiseOrValue<bool> {
@@ -767,7 +2115,7 @@ impl FluxProtocol {
         let mut market = self.markets.get(&market_id).expect("market doesn't exist");
 		
 		/* Resolute the market, which returns how much of the stake the sender overpaid */
-		let change = market.dispute_internal(sender.to_string(), winning_outcome, stake);
+		let change = market.dispute_internal(sender.to_string(), payout, stake);
 
 		self.markets.insert(&market.id, &market);
 		
@@ -781,18 +2129,25 @@ impl FluxProtocol {
 
 	/**
 	 * @notice Finalizes a market once disputed or the dispute window has been closed
-	 * @dev Panics if the market hasn't been resoluted yet
-	 *  Panics if the market is disputed and finalize is not called by the judge
+	 * @dev Panics if the market hasn't been resoluted yet, unless its resolution deadline has passed
+	 *  in that case anyone can finalize the market as invalid so all positions and open orders are refunded
+	 *  Panics if the market is disputed and finalize is not called by a judge, for disputed markets this call is a judge's vote
+	 *  and the market is only finalized once the judge threshold is met
+	 *  Panics if a judge votes twice or after finalization
 	 *	Panics if the dispute window is still open
 	 *	Panics if the winning_outcome is an invalid outcome
+	 *  Panics if the protocol is paused, nobody can dispute while the protocol is paused so nothing can be finalized either
 	 * @param market_id The id of the market to finalize
 	 * @param winning_outcome Optional in case the market has been disptud, the judges ruling
+	 * @param payout_numerators Optional in case the market has been disputed, the judges ruling as a payout numerator per outcome
 	 */
 	pub fn finalize_market(
 		&mut self, 
 		market_id: U64, 
-		winning_outcome: Option<U64>
+		winning_outcome: Option<U64>,
+		payout_numerators: Option<Vec<U128>>
 	) {
+		self.assert_not_paused();
 		let market_id: u64 = market_id.into();
 
 		/* Convert winning_outcome parameter into a Option<u64> */
@@ -802,21 +2157,127 @@ impl FluxProtocol {
 		};
 		
 		let mut market = self.markets.get(&market_id).unwrap();
-		assert!(winning_outcome == None || winning_outcome.unwrap() < market.outcomes, "invalid outcome");
+		let payout = market.to_payout(winning_outcome, self.to_payout_numerators(payout_numerators));
+
+		/* If nobody resoluted the market before the resolution deadline it's finalized as invalid */
+		if market.is_past_resolution_deadline() && !market.cancelled {
+			market.expire_internal(env::predecessor_account_id());
+			self.markets.insert(&market_id, &market);
+			self.invalidate_conditional_markets(&market);
+			return;
+		}
+
 		assert_eq!(market.resoluted, true, "market has to be resoluted before it can be finalized");
 
+		assert_eq!(market.finalized, false, "market is already finalized");
+
 		if market.disputed {
-			/* If the market is disputed this means that the market is to be finalized by the owner */
-			assert_eq!(env::predecessor_account_id(), self.owner, "only the judge can resolute disputed markets");
+			/* If the market is disputed this means that the market is to be finalized by the judge council, this call counts as the judge's vote */
+			let judge = env::predecessor_account_id();
+			assert!(self.judges.contains(&judge), "only the judge can resolute disputed markets");
+			market.judge_vote_internal(judge, &payout);
+
+			/* Only count the votes of the current council */
+			let votes = self.judges.iter().filter(|judge| market.judge_votes.get(judge).as_ref() == Some(&payout)).count() as u64;
+			logger::log_judge_vote_tally(market_id, &payout, votes, self.judge_threshold);
+
+			if votes < self.judge_threshold {
+				self.markets.insert(&market_id, &market);
+				return;
+			}
 		} else {
-			/* If the market is not disputed it can be resoluted as soon as the dispute window is closed */
+			/* If the market is not disputed it can be resoluted as soon as the latest dispute window is closed, the outcome of the last bonded window is final */
 			let dispute_window = market.resolution_windows.get(market.resolution_windows.len() - 1).expect("no dispute window found, something went wrong");
-			assert!(env::block_timestamp() / 1000000 >= dispute_window.end_time || dispute_window.round == 2, "dispute window still open")
+			assert!(env::block_timestamp() / 1000000 >= dispute_window.end_time, "dispute window still open")
 		}
 
 		/* Finalize the market and re-insert it to update state */
-		market.finalize_internal(winning_outcome);
+		market.finalize_internal(payout);
 		self.markets.insert(&market_id, &market);
+
+		self.invalidate_conditional_markets(&market);
+	}
+
+	/**
+	 * @notice Adds a market to the category and creator indexes
+	 */
+	fn index_market(
+		&mut self,
+		market: &Market
+	) {
+		for category in market.categories.iter() {
+			let mut market_ids = self.markets_by_category.get(&category).unwrap_or(new_market_index("markets_by_category", &category));
+			market_ids.insert(&market.id, &true);
+			self.markets_by_category.insert(&category, &market_ids);
+		}
+
+		let mut market_ids = self.markets_by_creator.get(&market.creator).unwrap_or(new_market_index("markets_by_creator", &market.creator));
+		market_ids.insert(&market.id, &true);
+		self.markets_by_creator.insert(&market.creator, &market_ids);
+	}
+
+	/**
+	 * @notice Removes a market from the category and creator indexes
+	 */
+	fn unindex_market(
+		&mut self,
+		market: &Market
+	) {
+		for category in market.categories.iter() {
+			if let Some(mut market_ids) = self.markets_by_category.get(&category) {
+				market_ids.remove(&market.id);
+				self.markets_by_category.insert(&category, &market_ids);
+			}
+		}
+
+		if let Some(mut market_ids) = self.markets_by_creator.get(&market.creator) {
+			market_ids.remove(&market.id);
+			self.markets_by_creator.insert(&market.creator, &market_ids);
+		}
+	}
+
+	/**
+	 * @notice Returns whether a conditional market can still be created under parent_id
+	 * @return Returns false if the parent is finalized, cancelled or has reached the max amount of conditional markets
+	 */
+	fn can_add_conditional_market(
+		&self,
+		parent_id: u64
+	) -> bool {
+		let parent_market = match self.markets.get(&parent_id) {
+			Some(parent_market) => parent_market,
+			None => return false
+		};
+		let conditional_markets = self.conditional_markets.get(&parent_id).unwrap_or(vec![]);
+		return !parent_market.finalized && !parent_market.cancelled && conditional_markets.len() < MAX_CONDITIONAL_MARKETS;
+	}
+
+	/**
+	 * @notice Invalidates all conditional markets of a finalized parent market whose condition wasn't met
+	 * @dev Conditional markets whose condition was met get their resolution deadline started instead
+	 *  Conditional markets can't be parents themselves, so there's no need to recurse
+	 * @param parent_market A reference to the finalized parent market
+	 */
+	fn invalidate_conditional_markets(
+		&mut self,
+		parent_market: &Market
+	) {
+		let conditional_markets = self.conditional_markets.get(&parent_market.id).unwrap_or(vec![]);
+		let winning_outcome = parent_market.get_winning_outcome();
+
+		for market_id in conditional_markets {
+			let mut market = self.markets.get(&market_id).expect("conditional market doesn't exist");
+			let (_, parent_outcome) = market.parent.expect("market isn't a conditional market");
+
+			if market.finalized {continue;}
+
+			if winning_outcome == Some(parent_outcome) {
+				market.parent_finalized_at = Some(env::block_timestamp() / 1000000);
+			} else {
+				market.invalidate_by_parent_internal();
+			}
+			self.markets.insert(&market_id, &market);
+		}
 	}
 
 	/**
@@ -824,15 +2285,18 @@ impl FluxProtocol {
 	 * @dev Panics if sender don't have any stake in the market / round / outcome
 	 *  Panics if the market doesn't exist
 	 *	Only works as long as the total stake < the stake required for that round, afterwards the stake will be bonded and not withdrawable until market finalization
+	 *  Unbonded stake is paid out by claim_earnings as well, panics if the sender already claimed their earnings in this market
 	 * @param market_id The id of the market to withdraw the users stake from
 	 * @param dispute_round The round of resolution of dispute the user wants to withdraw from
 	 * @param outcome The outcome the user staked on
+	 * @param payout_numerators The payout numerators the user staked on if the stake was on a split payout
 	 */
 	pub fn withdraw_dispute_stake(
 		&mut self, 
 		market_id: U64,
 		dispute_round: U64,
-		outcome: Option<U64>
+		outcome: Option<U64>,
+		payout_numerators: Option<Vec<U128>>
 	) -> Promise {
 		let market_id: u64 = market_id.into();
 		let dispute_round: u64 = dispute_round.into();
@@ -844,13 +2308,15 @@ impl FluxProtocol {
 		};
 
 		let mut market = self.markets.get(&market_id).expect("invalid market");
-		let to_return = market.withdraw_resolution_stake_internal(dispute_round, outcome);
+		assert!(market.claimed_earnings.get(&env::predecessor_account_id()).is_none(), "unbonded stake was already paid out by claim_earnings");
+		let payout = market.to_payout(outcome, self.to_payout_numerators(payout_numerators));
+		let to_return = market.withdraw_resolution_stake_internal(dispute_round, payout.to_vec());
 
 		/* If the user has stake to withdraw transfer the stake back to the user */
 		if to_return > 0 {
 			/* Re-insert the market into the markets struct to update state */
 			self.markets.insert(&market_id, &market);
-			logger::log_dispute_withdraw(market_id, env::predecessor_account_id(), dispute_round, outcome);
+			logger::log_dispute_withdraw(market_id, env::predecessor_account_id(), dispute_round, &payout);
 			return fun_token::transfer(env::predecessor_account_id(), U128(to_return), &self.fun_token_account_id(), 0, SINGLE_CALL_GAS);
 		} else {
 			panic!("user has no participation in this dispute");
@@ -860,7 +2326,7 @@ impl FluxProtocol {
 	/**
 	 * @notice Claims a users earnings in a finalized market
 	 * @dev Panics if user already claimed earnigns
-	 *  Panics if the market is not finalized
+	 *  Panics if the market is not finalized, unless it was cancelled
 	 *  Panics if the user has 0 tokens to claim
 	 * @param market_id The id of the market that earnings are going to be claimed for
 	 * @param account_id The account_id of the user to claim earnings for
@@ -877,9 +2343,12 @@ impl FluxProtocol {
 		/* Check if account_id has claimed earnings in this market, if so return 0 */
 		let claimed_earnings = market.claimed_earnings.get(&account_id);
 		assert_eq!(claimed_earnings.is_none(), true, "user already claimed earnings");
-		assert!(env::block_timestamp() / 1000000 >= market.end_time, "market hasn't ended yet");
-		assert_eq!(market.resoluted, true, "market isn't resoluted yet");
-		assert_eq!(market.finalized, true, "market isn't finalized yet");
+		/* Cancelled markets are never resoluted, open orders of a cancelled market can be claimed right away */
+		if !market.cancelled {
+			assert!(env::block_timestamp() / 1000000 >= market.end_time || market.invalidated_by_parent, "market hasn't ended yet");
+			assert_eq!(market.resoluted, true, "market isn't resoluted yet");
+			assert_eq!(market.finalized, true, "market isn't finalized yet");
+		}
 
 		/* Make sure it is noted that user claimed earnings to avoid double claims */
 		market.claimed_earnings.insert(&account_id, &true);
@@ -888,26 +2357,25 @@ impl FluxProtocol {
 		let (winnings, left_in_open_orders, governance_earnings) = market.get_claimable_internal(account_id.to_string());
 
 		/* If account_id is the market creator, and if the market was resoluted as being valid. If this is the case account_id is eligable to receive the validity bond back */ 
-		let mut validity_bond = 0;
-		if account_id == market.creator && market.validity_bond_claimed == false && market.winning_outcome != None {
-			validity_bond = self.creation_bond;
-			market.validity_bond_claimed = true;			
+		let validity_bond = market.get_validity_bond_claimable(&account_id);
+		if account_id == market.creator && validity_bond > 0 {
+			market.validity_bond_claimed = true;
 		}
 
-		let claimable_if_invalid = match market.winning_outcome {
-			None =>  market.claimable_if_invalid.get(&account_id).unwrap_or(0),
-			_ => 0
+		let claimable_if_invalid = match market.is_valid() {
+			false =>  market.claimable_if_invalid.get(&account_id).unwrap_or(0),
+			true => 0
 		};
-		let claimable_if_valid = match market.winning_outcome {
-			Some(_) =>  market.claimable_if_valid.get(&account_id).unwrap_or(0),
-			_ => 0
+		let claimable_if_valid = match market.is_valid() {
+			true =>  market.claimable_if_valid.get(&account_id).unwrap_or(0),
+			false => 0
 		};
 
 		/* Calculate the sum of winnings + claimable_if_invalid to determain what amount of funds can be feed */
 		let total_feeable_amount = winnings + claimable_if_invalid;
 
 		/* Calculate total fee percentage */
-		let resolution_fee = (total_feeable_amount * market.resolution_fee_percentage + 10000 - 1) / 10000;
+		let resolution_fee = (total_feeable_amount * self.get_resolution_fee_percentage(&market) + 10000 - 1) / 10000;
 		let market_creator_fee = (total_feeable_amount * self.get_creator_fee_percentage(&market) + 10000 - 1) / 10000;
 		let total_fee = resolution_fee + market_creator_fee;
 
@@ -945,6 +2413,7 @@ mod tests {
     use near_sdk::{VMContext, testing_env};
 	use near_runtime_standalone::{RuntimeStandalone};
 	use near_primitives::transaction::{ExecutionStatus, ExecutionOutcome};
+	use serde_json::json;
 
 	fn to_dai(amt: u128) -> u128 {
 		return amt * 1e18 as u128;
@@ -1062,4 +2531,27 @@ mod tests {
 	mod validity_bond_tests;
 	mod fee_payout_tests;
 	mod market_dispute_tests;
+	mod scalar_market_tests;
+	mod payout_tests;
+	mod conditional_market_tests;
+	mod market_pause_tests;
+	mod protocol_pause_tests;
+	mod end_time_tests;
+	mod market_cancellation_tests;
+	mod market_listing_tests;
+	mod market_index_tests;
+	mod market_metadata_tests;
+	mod many_outcomes_tests;
+	mod creation_policy_tests;
+	mod protocol_config_tests;
+	mod roles_tests;
+	mod judge_council_tests;
+	mod dispute_escalation_tests;
+	mod dispute_window_tests;
+	mod resolution_bond_tests;
+	mod designated_reporter_tests;
+	mod oracle_tests;
+	mod resolution_source_tests;
+	mod resolution_deadline_tests;
+	mod resolution_stake_tests;
 }
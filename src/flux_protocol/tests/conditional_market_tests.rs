@@ -0,0 +1,102 @@
+use super::*;
+
+#[test]
+fn test_conditional_market_invalidated_by_parent() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+
+	alice.transfer(&mut runtime, carol.get_account_id(), to_dai(30).into()).expect("transfer failed couldn't be set");
+	alice.transfer(&mut runtime, root.get_account_id(), to_dai(30).into()).expect("transfer failed couldn't be set");
+	root.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	carol.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	let tx_res = alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	assert_eq!(tx_res.status, ExecutionStatus::SuccessValue(b"0".to_vec()));
+	let tx_res = alice.create_conditional_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string(), (U64(0), U64(0))).unwrap();
+	assert_eq!(tx_res.status, ExecutionStatus::SuccessValue(b"1".to_vec()));
+
+	alice.place_order(&mut runtime, U64(1), U64(0), U128(1000), U128(60), None).expect("order placement failed unexpectedly");
+	carol.place_order(&mut runtime, U64(1), U64(1), U128(1000), U128(40), None).expect("order placement failed unexpectedly");
+
+	runtime.current_block().block_timestamp = market_end_timestamp_ns();
+	root.resolute_market(&mut runtime, U64(0), Some(U64(1)), U128(to_dai(5))).expect("market resolution failed unexpectedly");
+	runtime.current_block().block_timestamp = market_end_timestamp_ns() + 43200000000000;
+	root.finalize_market(&mut runtime, U64(0), None).expect("market finalization failed unexpectedly");
+
+	let claimable_alice: u128 = alice.get_claimable(&mut runtime, U64(1), alice.get_account_id()).into();
+	let claimable_carol: u128 = alice.get_claimable(&mut runtime, U64(1), carol.get_account_id()).into();
+
+	/* Nobody resoluted the conditional market so there's no resolution fee */
+	let validity_bond = to_dai(25) / 100;
+	assert_eq!(claimable_alice, 60000 + validity_bond);
+	assert_eq!(claimable_carol, 40000);
+
+	carol.claim_earnings(&mut runtime, U64(1), carol.get_account_id()).expect("claim earnings failed unexpectedly");
+}
+
+#[test]
+#[should_panic(expected = "parent market isn't finalized yet")]
+fn test_conditional_market_resolution_before_parent_finalization() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	alice.create_conditional_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string(), (U64(0), U64(0))).unwrap();
+
+	runtime.current_block().block_timestamp = market_end_timestamp_ns();
+	alice.resolute_market(&mut runtime, U64(1), Some(U64(0)), U128(to_dai(5))).expect("market resolution failed unexpectedly");
+}
+
+#[test]
+#[should_panic(expected = "conditional markets can't be used as parent market")]
+fn test_nested_conditional_market() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	alice.create_conditional_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string(), (U64(0), U64(0))).unwrap();
+	alice.create_conditional_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string(), (U64(1), U64(0))).expect("market creation failed as expected");
+}
+
+#[test]
+#[should_panic(expected = "parent market has reached the max amount of conditional markets")]
+fn test_max_conditional_markets() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	for _ in 0..16 {
+		alice.create_conditional_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string(), (U64(0), U64(0))).expect("market creation failed unexpectedly");
+	}
+	alice.create_conditional_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string(), (U64(0), U64(0))).expect("market creation failed as expected");
+}
+
+#[test]
+fn test_conditional_market_deadline_starts_at_parent_finalization() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.transfer(&mut runtime, root.get_account_id(), to_dai(30).into()).expect("transfer failed couldn't be set");
+	root.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	/* The parent ends on the conditional market's resolution deadline (30 days after its end_time) */
+	let resolution_deadline_ms = 2592000000;
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms() + resolution_deadline_ms), U128(0), U128(0), "test".to_string()).unwrap();
+	alice.create_conditional_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string(), (U64(0), U64(0))).unwrap();
+
+	/* The conditional market can't be resoluted before its parent finalizes, so it doesn't expire either */
+	runtime.current_block().block_timestamp = (market_end_timestamp_ms() + resolution_deadline_ms) * 1000000;
+	assert!(root.finalize_market(&mut runtime, U64(1), None).is_err());
+
+	root.resolute_market(&mut runtime, U64(0), Some(U64(0)), U128(to_dai(5))).expect("market resolution failed unexpectedly");
+	runtime.current_block().block_timestamp = (market_end_timestamp_ms() + resolution_deadline_ms) * 1000000 + 43200000000000;
+	root.finalize_market(&mut runtime, U64(0), None).expect("market finalization failed unexpectedly");
+
+	root.resolute_market(&mut runtime, U64(1), Some(U64(1)), U128(to_dai(5))).expect("market resolution failed unexpectedly");
+	assert_eq!(alice.get_markets(&runtime, U64(1), U64(1), None)[0]["resoluted"], true);
+}
@@ -0,0 +1,95 @@
+use super::*;
+
+#[test]
+fn test_approval_policy() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	root.set_creation_policy(&mut runtime, "approval".to_string()).expect("setting the creation policy failed unexpectedly");
+
+	let balance_before_proposals: u128 = alice.get_balance(&mut runtime, alice.get_account_id()).into();
+
+	let tx_res = alice.propose_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	assert_eq!(tx_res.status, ExecutionStatus::SuccessValue(b"0".to_vec()));
+	let tx_res = alice.propose_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	assert_eq!(tx_res.status, ExecutionStatus::SuccessValue(b"1".to_vec()));
+
+	/* Proposals aren't markets until they're approved */
+	assert_eq!(alice.get_markets(&runtime, U64(0), U64(10), None).len(), 0);
+
+	let tx_res = root.approve_market_proposal(&mut runtime, U64(1)).expect("approving the proposal failed unexpectedly");
+	assert_eq!(tx_res.status, ExecutionStatus::SuccessValue(b"0".to_vec()));
+	root.reject_market_proposal(&mut runtime, U64(0), "duplicate market".to_string()).expect("rejecting the proposal failed unexpectedly");
+
+	let markets = alice.get_markets(&runtime, U64(0), U64(10), None);
+	assert_eq!(markets.len(), 1);
+	assert_eq!(markets[0]["creator"], json!(alice.get_account_id()));
+
+	/* Only the bond of the approved market is still escrowed */
+	let balance_after_rejection: u128 = alice.get_balance(&mut runtime, alice.get_account_id()).into();
+	assert_eq!(balance_after_rejection, balance_before_proposals - to_dai(25) / 100);
+}
+
+#[test]
+#[should_panic(expected = "markets have to be proposed through propose_market under the approval policy")]
+fn test_create_market_under_approval_policy() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	root.set_creation_policy(&mut runtime, "approval".to_string()).expect("setting the creation policy failed unexpectedly");
+
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).expect("market creation failed unexpectedly");
+}
+
+#[test]
+fn test_withdraw_market_proposal() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	root.set_creation_policy(&mut runtime, "approval".to_string()).expect("setting the creation policy failed unexpectedly");
+
+	let balance_before_proposal: u128 = alice.get_balance(&mut runtime, alice.get_account_id()).into();
+	alice.propose_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).expect("proposing the market failed unexpectedly");
+
+	/* Only the proposer can withdraw the proposal */
+	assert!(carol.withdraw_market_proposal(&mut runtime, U64(0)).is_err());
+	alice.withdraw_market_proposal(&mut runtime, U64(0)).expect("withdrawing the proposal failed unexpectedly");
+
+	let balance_after_withdrawal: u128 = alice.get_balance(&mut runtime, alice.get_account_id()).into();
+	assert_eq!(balance_after_withdrawal, balance_before_proposal);
+	assert!(root.approve_market_proposal(&mut runtime, U64(0)).is_err());
+}
+
+#[test]
+#[should_panic(expected = "protocol is paused")]
+fn test_approve_market_proposal_while_paused() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	root.set_creation_policy(&mut runtime, "approval".to_string()).expect("setting the creation policy failed unexpectedly");
+
+	alice.propose_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).expect("proposing the market failed unexpectedly");
+	root.pause(&mut runtime, "matching bug".to_string()).expect("pausing the protocol failed unexpectedly");
+
+	root.approve_market_proposal(&mut runtime, U64(0)).expect("approving the proposal failed unexpectedly");
+}
+
+#[test]
+fn test_allowlist_policy() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+	alice.transfer(&mut runtime, carol.get_account_id(), to_dai(30).into()).expect("transfer failed couldn't be set");
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	carol.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	root.set_creation_policy(&mut runtime, "allowlist".to_string()).expect("setting the creation policy failed unexpectedly");
+	root.set_allowlisted_creator(&mut runtime, alice.get_account_id(), true).expect("updating the allowlist failed unexpectedly");
+
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).expect("market creation failed unexpectedly");
+	let tx_res = carol.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string());
+	assert!(tx_res.is_err());
+
+	assert_eq!(alice.get_markets(&runtime, U64(0), U64(10), None).len(), 1);
+}
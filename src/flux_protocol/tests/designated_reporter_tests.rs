@@ -0,0 +1,108 @@
+use super::*;
+
+fn init_reporter_env(runtime: &mut RuntimeStandalone, root: &ExternalUser, accounts: &Vec<ExternalUser>) {
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+	alice.transfer(runtime, carol.get_account_id(), to_dai(30).into()).expect("transfer failed couldn't be set");
+	alice.transfer(runtime, root.get_account_id(), to_dai(30).into()).expect("transfer failed couldn't be set");
+	alice.set_allowance(runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	carol.set_allowance(runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	root.set_allowance(runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market_with_options(runtime, json!({"designated_reporter": carol.get_account_id()})).expect("market creation failed unexpectedly");
+	runtime.current_block().block_timestamp = market_end_timestamp_ns();
+}
+
+#[test]
+fn test_designated_reporter_resolutes_with_reduced_bond() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	init_reporter_env(&mut runtime, &root, &accounts);
+	let carol = &accounts[1];
+
+	/* Without a designated reporter bond percentage the reporter only has to stake the min stake */
+	let balance_before_resolution: u128 = carol.get_balance(&mut runtime, carol.get_account_id()).into();
+	carol.resolute_market(&mut runtime, U64(0), Some(U64(1)), U128(to_dai(5))).expect("market resolution failed unexpectedly");
+	let balance_after_resolution: u128 = carol.get_balance(&mut runtime, carol.get_account_id()).into();
+
+	assert_eq!(balance_before_resolution - balance_after_resolution, to_dai(1) / 100);
+	assert_eq!(carol.get_markets(&runtime, U64(0), U64(1), None)[0]["resoluted"], true);
+}
+
+#[test]
+#[should_panic(expected = "only the designated reporter can resolute during the reporting window")]
+fn test_public_resolution_during_reporting_window() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	init_reporter_env(&mut runtime, &root, &accounts);
+
+	root.resolute_market(&mut runtime, U64(0), Some(U64(1)), U128(to_dai(5))).expect("market resolution failed as expected");
+}
+
+#[test]
+fn test_first_public_reporter_is_rewarded_from_validity_bond() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	init_reporter_env(&mut runtime, &root, &accounts);
+	let alice = &accounts[0];
+
+	/* The designated reporter doesn't report within 24 hours, the market falls back to open staking with the full bond */
+	runtime.current_block().block_timestamp = market_end_timestamp_ns() + 86400000000000;
+	root.resolute_market(&mut runtime, U64(0), Some(U64(1)), U128(to_dai(5))).expect("market resolution failed unexpectedly");
+
+	runtime.current_block().block_timestamp = market_end_timestamp_ns() + 86400000000000 + 43200000000000;
+	root.finalize_market(&mut runtime, U64(0), None).expect("market finalization failed unexpectedly");
+
+	/* Half of the validity bond is slashed in favor of the public reporter */
+	let validity_bond = to_dai(25) / 100;
+	let claimable_root: u128 = root.get_claimable(&mut runtime, U64(0), root.get_account_id()).into();
+	let claimable_alice: u128 = alice.get_claimable(&mut runtime, U64(0), alice.get_account_id()).into();
+	assert_eq!(claimable_root, to_dai(5) + validity_bond / 2);
+	assert_eq!(claimable_alice, validity_bond / 2);
+}
+
+#[test]
+fn test_late_designated_reporter_is_slashed() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	init_reporter_env(&mut runtime, &root, &accounts);
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+
+	/* The designated reporter reports after its window, the validity bond is slashed without rewarding the reporter */
+	runtime.current_block().block_timestamp = market_end_timestamp_ns() + 86400000000000;
+	carol.resolute_market(&mut runtime, U64(0), Some(U64(1)), U128(to_dai(5))).expect("market resolution failed unexpectedly");
+
+	runtime.current_block().block_timestamp = market_end_timestamp_ns() + 86400000000000 + 43200000000000;
+	root.finalize_market(&mut runtime, U64(0), None).expect("market finalization failed unexpectedly");
+
+	let validity_bond = to_dai(25) / 100;
+	let claimable_carol: u128 = carol.get_claimable(&mut runtime, U64(0), carol.get_account_id()).into();
+	let claimable_alice: u128 = alice.get_claimable(&mut runtime, U64(0), alice.get_account_id()).into();
+	assert_eq!(claimable_carol, to_dai(5));
+	assert_eq!(claimable_alice, validity_bond / 2);
+}
+
+#[test]
+fn test_late_reporting_creator_is_slashed() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	init_reporter_env(&mut runtime, &root, &accounts);
+	let alice = &accounts[0];
+
+	/* The creator reports after the designated reporter's window, the slashed part of the validity bond isn't paid back to the creator */
+	runtime.current_block().block_timestamp = market_end_timestamp_ns() + 86400000000000;
+	alice.resolute_market(&mut runtime, U64(0), Some(U64(1)), U128(to_dai(5))).expect("market resolution failed unexpectedly");
+
+	runtime.current_block().block_timestamp = market_end_timestamp_ns() + 86400000000000 + 43200000000000;
+	root.finalize_market(&mut runtime, U64(0), None).expect("market finalization failed unexpectedly");
+
+	let validity_bond = to_dai(25) / 100;
+	let claimable_alice: u128 = alice.get_claimable(&mut runtime, U64(0), alice.get_account_id()).into();
+	assert_eq!(claimable_alice, to_dai(5) + validity_bond / 2);
+}
+
+#[test]
+#[should_panic(expected = "invalid designated reporter account id")]
+fn test_invalid_designated_reporter() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market_with_options(&mut runtime, json!({"designated_reporter": "Not An Account"})).expect("market creation failed as expected");
+}
@@ -0,0 +1,69 @@
+use super::*;
+
+fn init_escalation_env(runtime: &mut RuntimeStandalone, root: &ExternalUser, accounts: &Vec<ExternalUser>, max_dispute_rounds: u64) {
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+	alice.transfer(runtime, carol.get_account_id(), to_dai(50).into()).expect("transfer failed couldn't be set");
+	alice.set_allowance(runtime, flux_protocol(), U128(to_dai(50))).expect("allowance couldn't be set");
+	carol.set_allowance(runtime, flux_protocol(), U128(to_dai(50))).expect("allowance couldn't be set");
+
+	root.set_config(runtime, json!({"max_dispute_rounds": U64(max_dispute_rounds)})).expect("updating the config failed unexpectedly");
+	alice.create_market(runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	runtime.current_block().block_timestamp = market_end_timestamp_ns();
+}
+
+#[test]
+fn test_undisputed_escalation_round_is_final() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	init_escalation_env(&mut runtime, &root, &accounts, 3);
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+
+	carol.resolute_market(&mut runtime, U64(0), Some(U64(0)), U128(to_dai(5))).expect("market resolution failed unexpectedly");
+	alice.dispute_market(&mut runtime, U64(0), Some(U64(1)), U128(to_dai(10))).expect("market dispute failed unexpectedly");
+	/* Every round doubles the bond of the previous round */
+	carol.dispute_market(&mut runtime, U64(0), Some(U64(0)), U128(to_dai(20))).expect("market dispute failed unexpectedly");
+
+	/* The third dispute window closes undisputed so anyone can finalize on the last bonded outcome */
+	runtime.current_block().block_timestamp = market_end_timestamp_ns() + 43200000000000;
+	alice.finalize_market(&mut runtime, U64(0), None).expect("market finalization failed unexpectedly");
+
+	/* Carol gets her stake in both rounds back plus alice's bond from the round she was wrong in */
+	let claimable_carol: u128 = carol.get_claimable(&mut runtime, U64(0), carol.get_account_id()).into();
+	assert_eq!(claimable_carol, to_dai(5) + to_dai(20) + to_dai(10));
+
+	let validity_bond = to_dai(25) / 100;
+	let claimable_alice: u128 = alice.get_claimable(&mut runtime, U64(0), alice.get_account_id()).into();
+	assert_eq!(claimable_alice, validity_bond);
+}
+
+#[test]
+#[should_panic(expected = "dispute rounds exhausted, market has to be finalized by the judge council")]
+fn test_judge_decides_after_max_dispute_rounds() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	init_escalation_env(&mut runtime, &root, &accounts, 2);
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+
+	carol.resolute_market(&mut runtime, U64(0), Some(U64(0)), U128(to_dai(5))).expect("market resolution failed unexpectedly");
+	alice.dispute_market(&mut runtime, U64(0), Some(U64(1)), U128(to_dai(10))).expect("market dispute failed unexpectedly");
+	carol.dispute_market(&mut runtime, U64(0), Some(U64(0)), U128(to_dai(20))).expect("market dispute failed unexpectedly");
+
+	alice.dispute_market(&mut runtime, U64(0), Some(U64(1)), U128(to_dai(40))).expect("market dispute failed as expected");
+}
+
+#[test]
+#[should_panic(expected = "dispute window still open")]
+fn test_escalation_round_restarts_dispute_window() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	init_escalation_env(&mut runtime, &root, &accounts, 3);
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+
+	carol.resolute_market(&mut runtime, U64(0), Some(U64(0)), U128(to_dai(5))).expect("market resolution failed unexpectedly");
+	runtime.current_block().block_timestamp = market_end_timestamp_ns() + 43200000000000 - 1000000000;
+	alice.dispute_market(&mut runtime, U64(0), Some(U64(1)), U128(to_dai(10))).expect("market dispute failed unexpectedly");
+
+	runtime.current_block().block_timestamp = market_end_timestamp_ns() + 43200000000000;
+	alice.finalize_market(&mut runtime, U64(0), None).expect("market finalization failed as expected");
+}
@@ -0,0 +1,47 @@
+use super::*;
+
+#[test]
+fn test_custom_dispute_window() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	/* 2 hour dispute window */
+	alice.create_market_with_options(&mut runtime, json!({"dispute_window": U64(7200000)})).expect("market creation failed unexpectedly");
+
+	runtime.current_block().block_timestamp = market_end_timestamp_ns();
+	alice.resolute_market(&mut runtime, U64(0), Some(U64(1)), U128(to_dai(5))).expect("market resolution failed unexpectedly");
+
+	runtime.current_block().block_timestamp = market_end_timestamp_ns() + 7200000000000;
+	alice.finalize_market(&mut runtime, U64(0), None).expect("market finalization failed unexpectedly");
+}
+
+#[test]
+#[should_panic(expected = "dispute window still open")]
+fn test_escalation_rounds_use_market_dispute_window() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	root.set_config(&mut runtime, json!({"max_dispute_rounds": U64(2)})).expect("updating the config failed unexpectedly");
+
+	/* 3 day dispute window */
+	alice.create_market_with_options(&mut runtime, json!({"dispute_window": U64(259200000)})).expect("market creation failed unexpectedly");
+
+	runtime.current_block().block_timestamp = market_end_timestamp_ns();
+	alice.resolute_market(&mut runtime, U64(0), Some(U64(1)), U128(to_dai(5))).expect("market resolution failed unexpectedly");
+	alice.dispute_market(&mut runtime, U64(0), Some(U64(0)), U128(to_dai(10))).expect("market dispute failed unexpectedly");
+
+	/* The protocol's default 12 hour window has passed but the market's window hasn't */
+	runtime.current_block().block_timestamp = market_end_timestamp_ns() + 43200000000000;
+	alice.finalize_market(&mut runtime, U64(0), None).expect("market finalization failed as expected");
+}
+
+#[test]
+#[should_panic(expected = "dispute_window has to be within the dispute window bounds")]
+fn test_dispute_window_out_of_bounds() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market_with_options(&mut runtime, json!({"dispute_window": U64(60000)})).expect("market creation failed as expected");
+}
@@ -0,0 +1,73 @@
+use super::*;
+
+#[test]
+fn test_close_market_early() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.transfer(&mut runtime, root.get_account_id(), to_dai(30).into()).expect("transfer failed couldn't be set");
+	root.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+
+	let early_end_timestamp_ms = market_end_timestamp_ms() - 5;
+	runtime.current_block().block_timestamp = early_end_timestamp_ms * 1000000;
+	alice.update_market_end_time(&mut runtime, U64(0), U64(early_end_timestamp_ms)).expect("updating end_time failed unexpectedly");
+
+	let tx_res = alice.place_order(&mut runtime, U64(0), U64(0), U128(1000), U128(50), None);
+	assert!(tx_res.is_err());
+
+	root.resolute_market(&mut runtime, U64(0), Some(U64(1)), U128(to_dai(5))).expect("market resolution failed unexpectedly");
+}
+
+#[test]
+#[should_panic(expected = "market hasn't ended yet")]
+fn test_extend_market_end_time() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.transfer(&mut runtime, root.get_account_id(), to_dai(30).into()).expect("transfer failed couldn't be set");
+	root.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	alice.update_market_end_time(&mut runtime, U64(0), U64(market_end_timestamp_ms() + 3600000)).expect("updating end_time failed unexpectedly");
+
+	runtime.current_block().block_timestamp = market_end_timestamp_ns();
+	root.resolute_market(&mut runtime, U64(0), Some(U64(1)), U128(to_dai(5))).expect("market resolution failed unexpectedly");
+}
+
+#[test]
+#[should_panic(expected = "end_time can't be extended by more than 30 days")]
+fn test_extend_market_end_time_out_of_bounds() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	alice.update_market_end_time(&mut runtime, U64(0), U64(market_end_timestamp_ms() + 2592000001)).expect("updating end_time failed unexpectedly");
+}
+
+#[test]
+#[should_panic(expected = "end_time can't be extended by more than 30 days")]
+fn test_repeated_extensions_are_capped() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	alice.update_market_end_time(&mut runtime, U64(0), U64(market_end_timestamp_ms() + 2592000000)).expect("updating end_time failed unexpectedly");
+	/* The 30 days are measured from the original end_time, not from the extended one */
+	alice.update_market_end_time(&mut runtime, U64(0), U64(market_end_timestamp_ms() + 2592000001)).expect("updating end_time failed as expected");
+}
+
+#[test]
+#[should_panic(expected = "market has already ended")]
+fn test_extend_closed_market() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	runtime.current_block().block_timestamp = market_end_timestamp_ns() + 1000000;
+	alice.update_market_end_time(&mut runtime, U64(0), U64(market_end_timestamp_ms() + 3600000)).expect("updating end_time failed as expected");
+}
@@ -0,0 +1,80 @@
+use super::*;
+
+fn init_disputed_market(runtime: &mut RuntimeStandalone, root: &ExternalUser, accounts: &Vec<ExternalUser>) {
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+	alice.transfer(runtime, root.get_account_id(), to_dai(30).into()).expect("transfer failed couldn't be set");
+	root.set_allowance(runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	alice.set_allowance(runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	root.set_judges(runtime, vec![root.get_account_id(), alice.get_account_id(), carol.get_account_id()], U64(2)).expect("setting the judges failed unexpectedly");
+
+	alice.create_market(runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	runtime.current_block().block_timestamp = market_end_timestamp_ns();
+	root.resolute_market(runtime, U64(0), Some(U64(1)), U128(to_dai(5))).expect("market resolution failed unexpectedly");
+	alice.dispute_market(runtime, U64(0), Some(U64(0)), U128(to_dai(10))).expect("market dispute failed unexpectedly");
+}
+
+#[test]
+fn test_market_finalizes_once_threshold_is_met() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	init_disputed_market(&mut runtime, &root, &accounts);
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+
+	root.finalize_market(&mut runtime, U64(0), Some(U64(0))).expect("judge vote failed unexpectedly");
+	alice.finalize_market(&mut runtime, U64(0), Some(U64(1))).expect("judge vote failed unexpectedly");
+	/* Two votes on different outcomes don't meet the threshold */
+	assert_eq!(root.get_markets(&runtime, U64(0), U64(1), None)[0]["finalized"], false);
+
+	carol.finalize_market(&mut runtime, U64(0), Some(U64(0))).expect("judge vote failed unexpectedly");
+	assert_eq!(root.get_markets(&runtime, U64(0), U64(1), None)[0]["finalized"], true);
+}
+
+#[test]
+#[should_panic(expected = "judge has already voted")]
+fn test_judge_cant_vote_twice() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	init_disputed_market(&mut runtime, &root, &accounts);
+	let alice = &accounts[0];
+
+	root.finalize_market(&mut runtime, U64(0), Some(U64(1))).expect("judge vote failed unexpectedly");
+	alice.finalize_market(&mut runtime, U64(0), Some(U64(0))).expect("judge vote failed unexpectedly");
+	root.finalize_market(&mut runtime, U64(0), Some(U64(0))).expect("second judge vote failed as expected");
+}
+
+#[test]
+fn test_removed_judge_vote_isnt_tallied() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	init_disputed_market(&mut runtime, &root, &accounts);
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+
+	root.finalize_market(&mut runtime, U64(0), Some(U64(0))).expect("judge vote failed unexpectedly");
+	alice.finalize_market(&mut runtime, U64(0), Some(U64(1))).expect("judge vote failed unexpectedly");
+
+	/* Alice is removed from the council so her vote no longer counts towards the threshold */
+	root.set_judges(&mut runtime, vec![root.get_account_id(), carol.get_account_id()], U64(2)).expect("setting the judges failed unexpectedly");
+	carol.finalize_market(&mut runtime, U64(0), Some(U64(1))).expect("judge vote failed unexpectedly");
+	assert_eq!(root.get_markets(&runtime, U64(0), U64(1), None)[0]["finalized"], false);
+}
+
+#[test]
+#[should_panic(expected = "market is already finalized")]
+fn test_judge_cant_vote_after_finalization() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	init_disputed_market(&mut runtime, &root, &accounts);
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+
+	root.finalize_market(&mut runtime, U64(0), Some(U64(0))).expect("judge vote failed unexpectedly");
+	alice.finalize_market(&mut runtime, U64(0), Some(U64(0))).expect("judge vote failed unexpectedly");
+	carol.finalize_market(&mut runtime, U64(0), Some(U64(1))).expect("judge vote failed as expected");
+}
+
+#[test]
+#[should_panic(expected = "invalid judge threshold")]
+fn test_threshold_higher_than_council() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	root.set_judges(&mut runtime, vec![accounts[0].get_account_id()], U64(2)).expect("setting the judges failed as expected");
+}
@@ -0,0 +1,40 @@
+use super::*;
+
+#[test]
+fn test_matching_with_many_outcomes() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+	alice.transfer(&mut runtime, carol.get_account_id(), to_dai(30).into()).expect("transfer failed couldn't be set");
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	carol.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	let tx_res = alice.create_market(&mut runtime, empty_string(), empty_string(), U64(40), outcome_tags(40), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	assert_eq!(tx_res.status, ExecutionStatus::SuccessValue(b"0".to_vec()));
+
+	/* Only 19 of the 40 outcomes get liquidity */
+	for outcome in 1..20 {
+		alice.place_order(&mut runtime, U64(0), U64(outcome), U128(2000), U128(5), None).expect("order placement failed unexpectedly");
+	}
+
+	assert_eq!(alice.get_market_price(&runtime, U64(0), U64(0)), U128(5));
+	assert_eq!(alice.get_market_price(&runtime, U64(0), U64(1)), U128(10));
+	assert_eq!(alice.get_market_price(&runtime, U64(0), U64(39)), U128(5));
+
+	carol.place_order(&mut runtime, U64(0), U64(0), U128(2000), U128(5), None).expect("order placement failed unexpectedly");
+
+	let filled_volume: u128 = alice.get_market_volume(&mut runtime, U64(0)).into();
+	assert_eq!(filled_volume, 200000);
+	assert_eq!(alice.get_market_price(&runtime, U64(0), U64(0)), U128(100));
+	assert_eq!(alice.get_outcome_share_balance(&runtime, carol.get_account_id(), U64(0), U64(0)), U128(2000));
+}
+
+#[test]
+#[should_panic(expected = "can't have more than 64 outcomes")]
+fn test_too_many_outcomes() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(65), outcome_tags(65), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).expect("market creation failed unexpectedly");
+}
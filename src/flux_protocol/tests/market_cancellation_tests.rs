@@ -0,0 +1,68 @@
+use super::*;
+
+#[test]
+fn test_cancel_untraded_market() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+	alice.transfer(&mut runtime, carol.get_account_id(), to_dai(30).into()).expect("transfer failed couldn't be set");
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	carol.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	let alice_balance_before_creation: u128 = alice.get_balance(&mut runtime, alice.get_account_id()).into();
+	let carol_balance_before_orders: u128 = carol.get_balance(&mut runtime, carol.get_account_id()).into();
+
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(3), outcome_tags(3), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	carol.place_order(&mut runtime, U64(0), U64(0), U128(1000), U128(20), None).expect("order placement failed unexpectedly");
+	carol.place_order(&mut runtime, U64(0), U64(1), U128(1000), U128(30), None).expect("order placement failed unexpectedly");
+	carol.place_order(&mut runtime, U64(0), U64(2), U128(1000), U128(40), None).expect("order placement failed unexpectedly");
+
+	alice.cancel_market(&mut runtime, U64(0)).expect("market cancellation failed unexpectedly");
+
+	/* Open orders are pulled by their owners, either per order or all at once through claim_earnings */
+	carol.cancel_order(&mut runtime, U64(0), U64(0), U128(20), U128(0)).expect("order cancelation failed");
+	carol.claim_earnings(&mut runtime, U64(0), carol.get_account_id()).expect("claim earnings failed unexpectedly");
+
+	let alice_balance: u128 = alice.get_balance(&mut runtime, alice.get_account_id()).into();
+	let carol_balance: u128 = carol.get_balance(&mut runtime, carol.get_account_id()).into();
+	let contract_balance: u128 = alice.get_balance(&mut runtime, flux_protocol()).into();
+	assert_eq!(alice_balance, alice_balance_before_creation);
+	assert_eq!(carol_balance, carol_balance_before_orders);
+	assert_eq!(contract_balance, 0);
+
+	let tx_res = carol.place_order(&mut runtime, U64(0), U64(0), U128(1000), U128(20), None);
+	assert!(tx_res.is_err());
+}
+
+#[test]
+#[should_panic(expected = "market has already been traded")]
+fn test_cancel_traded_market() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	alice.place_order(&mut runtime, U64(0), U64(0), U128(1000), U128(50), None).expect("order placement failed unexpectedly");
+	alice.place_order(&mut runtime, U64(0), U64(1), U128(1000), U128(50), None).expect("order placement failed unexpectedly");
+
+	alice.cancel_market(&mut runtime, U64(0)).expect("market cancellation failed unexpectedly");
+}
+
+#[test]
+fn test_cancel_market_keeps_open_orders_until_pulled() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+	alice.transfer(&mut runtime, carol.get_account_id(), to_dai(30).into()).expect("transfer failed couldn't be set");
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	carol.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	carol.place_order(&mut runtime, U64(0), U64(0), U128(1000), U128(20), None).expect("order placement failed unexpectedly");
+	alice.cancel_market(&mut runtime, U64(0)).expect("market cancellation failed unexpectedly");
+
+	let claimable_carol: u128 = carol.get_claimable(&mut runtime, U64(0), carol.get_account_id()).into();
+	let contract_balance: u128 = alice.get_balance(&mut runtime, flux_protocol()).into();
+	assert_eq!(claimable_carol, 20000);
+	assert_eq!(contract_balance, 20000);
+}
@@ -201,7 +201,7 @@ fn test_dispute_with_same_outcome() {
 }
 
 #[test]
-#[should_panic(expected = "for this version, there's only 1 round of dispute")]
+#[should_panic(expected = "dispute rounds exhausted, market has to be finalized by the judge council")]
 fn test_dispute_escalation_failure() {
 	let (mut runtime, root, accounts) = init_runtime_env();
 	accounts[0].set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
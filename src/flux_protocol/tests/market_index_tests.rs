@@ -0,0 +1,39 @@
+use super::*;
+
+#[test]
+fn test_category_index_normalization() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), vec!["Sports".to_string()], U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), vec!["crypto".to_string()], U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), vec!["sports ".to_string(), "SPORTS".to_string()], U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+
+	let sports_markets = alice.get_market_ids_by_category(&runtime, "sports".to_string());
+	assert_eq!(sports_markets, vec![U64(0), U64(2)]);
+	let crypto_markets = alice.get_market_ids_by_category(&runtime, "Crypto".to_string());
+	assert_eq!(crypto_markets, vec![U64(1)]);
+}
+
+#[test]
+fn test_creator_index() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+	alice.transfer(&mut runtime, carol.get_account_id(), to_dai(30).into()).expect("transfer failed couldn't be set");
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	carol.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	carol.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+
+	let alice_markets = alice.get_market_ids_by_creator(&runtime, alice.get_account_id());
+	assert_eq!(alice_markets, vec![U64(0), U64(2)]);
+
+	/* Cancelled markets are removed from the indexes */
+	alice.cancel_market(&mut runtime, U64(0)).expect("market cancellation failed unexpectedly");
+	let alice_markets = alice.get_market_ids_by_creator(&runtime, alice.get_account_id());
+	assert_eq!(alice_markets, vec![U64(2)]);
+}
@@ -0,0 +1,51 @@
+use super::*;
+
+#[test]
+fn test_get_markets_pagination() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	for _ in 0..3 {
+		alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	}
+	alice.place_order(&mut runtime, U64(1), U64(0), U128(1000), U128(40), None).expect("order placement failed unexpectedly");
+
+	let markets = alice.get_markets(&runtime, U64(1), U64(1), None);
+	assert_eq!(markets.len(), 1);
+	assert_eq!(markets[0]["id"], json!("1"));
+	assert_eq!(markets[0]["prices"], json!(["100", "60"]));
+
+	let markets = alice.get_markets(&runtime, U64(0), U64(10), None);
+	assert_eq!(markets.len(), 3);
+}
+
+#[test]
+fn test_get_markets_filters() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+	alice.transfer(&mut runtime, carol.get_account_id(), to_dai(30).into()).expect("transfer failed couldn't be set");
+	alice.transfer(&mut runtime, root.get_account_id(), to_dai(30).into()).expect("transfer failed couldn't be set");
+	root.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	carol.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), vec!["sports".to_string()], U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), vec!["crypto".to_string()], U64(market_end_timestamp_ms() + 1000), U128(0), U128(0), "test".to_string()).unwrap();
+	carol.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), vec!["sports".to_string()], U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+
+	runtime.current_block().block_timestamp = market_end_timestamp_ns();
+	root.resolute_market(&mut runtime, U64(0), Some(U64(1)), U128(to_dai(5))).expect("market resolution failed unexpectedly");
+
+	let sports_markets = alice.get_markets(&runtime, U64(0), U64(10), Some(json!({"category": "sports"})));
+	assert_eq!(sports_markets.len(), 2);
+
+	let open_sports_markets = alice.get_markets(&runtime, U64(0), U64(10), Some(json!({"category": "sports", "state": "open"})));
+	assert_eq!(open_sports_markets.len(), 1);
+	assert_eq!(open_sports_markets[0]["id"], json!("2"));
+
+	let alice_markets = alice.get_markets(&runtime, U64(0), U64(10), Some(json!({"creator": alice.get_account_id(), "end_time_from": U64(market_end_timestamp_ms() + 1)})));
+	assert_eq!(alice_markets.len(), 1);
+	assert_eq!(alice_markets[0]["id"], json!("1"));
+}
@@ -0,0 +1,93 @@
+use super::*;
+
+#[test]
+fn test_update_metadata_before_first_trade() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market(&mut runtime, "will it rian?".to_string(), empty_string(), U64(2), outcome_tags(0), vec!["weather".to_string()], U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	alice.update_market_metadata(&mut runtime, U64(0), Some("will it rain?".to_string()), None, None, Some(vec!["Climate".to_string()])).expect("updating metadata failed unexpectedly");
+
+	let markets = alice.get_markets(&runtime, U64(0), U64(1), None);
+	assert_eq!(markets[0]["description"], json!("will it rain?"));
+	assert_eq!(markets[0]["categories"], json!(["climate"]));
+	assert_eq!(alice.get_market_ids_by_category(&runtime, "weather".to_string()), Vec::<U64>::new());
+	assert_eq!(alice.get_market_ids_by_category(&runtime, "climate".to_string()), vec![U64(0)]);
+}
+
+#[test]
+#[should_panic(expected = "market has already been traded, only clarifications can be added")]
+fn test_update_metadata_after_first_trade() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	alice.place_order(&mut runtime, U64(0), U64(0), U128(1000), U128(50), None).expect("order placement failed unexpectedly");
+	alice.place_order(&mut runtime, U64(0), U64(1), U128(1000), U128(50), None).expect("order placement failed unexpectedly");
+
+	alice.update_market_metadata(&mut runtime, U64(0), Some("new description".to_string()), None, None, None).expect("updating metadata failed unexpectedly");
+}
+
+#[test]
+fn test_clarifications_are_appended() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market(&mut runtime, empty_string(), "source: weather.com".to_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	alice.place_order(&mut runtime, U64(0), U64(0), U128(1000), U128(50), None).expect("order placement failed unexpectedly");
+	alice.place_order(&mut runtime, U64(0), U64(1), U128(1000), U128(50), None).expect("order placement failed unexpectedly");
+
+	runtime.current_block().block_timestamp = market_creation_timestamp() * 1000000;
+	alice.add_market_clarification(&mut runtime, U64(0), "drizzle counts as rain".to_string()).expect("adding clarification failed unexpectedly");
+
+	let markets = alice.get_markets(&runtime, U64(0), U64(1), None);
+	assert_eq!(markets[0]["extra_info"], json!(format!("source: weather.com\n[{}] drizzle counts as rain", market_creation_timestamp())));
+}
+
+#[test]
+fn test_update_metadata_after_long_clarifications() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	/* The clarifications take extra_info past the 400 character limit of the base extra_info */
+	alice.add_market_clarification(&mut runtime, U64(0), "a".repeat(300)).expect("adding clarification failed unexpectedly");
+	alice.add_market_clarification(&mut runtime, U64(0), "b".repeat(300)).expect("adding clarification failed unexpectedly");
+
+	alice.update_market_metadata(&mut runtime, U64(0), Some("new description".to_string()), None, None, None).expect("updating metadata failed unexpectedly");
+	let markets = alice.get_markets(&runtime, U64(0), U64(1), None);
+	assert_eq!(markets[0]["description"], json!("new description"));
+}
+
+#[test]
+#[should_panic(expected = "market has open orders, only clarifications can be added")]
+fn test_update_metadata_with_open_orders() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	alice.place_order(&mut runtime, U64(0), U64(0), U128(1000), U128(50), None).expect("order placement failed unexpectedly");
+
+	alice.update_market_metadata(&mut runtime, U64(0), Some("new description".to_string()), None, None, None).expect("updating metadata failed unexpectedly");
+}
+
+#[test]
+#[should_panic(expected = "market has reached the max extra_info length")]
+fn test_clarifications_are_capped() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+
+	let clarification = "a".repeat(400);
+	for _ in 0..9 {
+		alice.add_market_clarification(&mut runtime, U64(0), clarification.to_string()).expect("adding clarification failed unexpectedly");
+	}
+	alice.add_market_clarification(&mut runtime, U64(0), clarification.to_string()).expect("adding clarification failed unexpectedly");
+}
@@ -0,0 +1,62 @@
+use super::*;
+
+#[test]
+fn test_cancel_order_on_paused_market() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	alice.place_order(&mut runtime, U64(0), U64(0), U128(1000), U128(50), None).expect("order placement failed unexpectedly");
+	let balance_before_cancel: u128 = alice.get_balance(&mut runtime, alice.get_account_id()).into();
+
+	alice.pause_market(&mut runtime, U64(0)).expect("market pause failed unexpectedly");
+	alice.cancel_order(&mut runtime, U64(0), U64(0), U128(50), U128(0)).expect("order cancelation failed");
+
+	let balance_after_cancel: u128 = alice.get_balance(&mut runtime, alice.get_account_id()).into();
+	assert_eq!(balance_after_cancel, balance_before_cancel + 50000);
+}
+
+#[test]
+#[should_panic(expected = "market is paused")]
+fn test_place_order_on_paused_market() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	root.pause_market(&mut runtime, U64(0)).expect("market pause failed unexpectedly");
+	alice.place_order(&mut runtime, U64(0), U64(0), U128(1000), U128(50), None).expect("order placement failed unexpectedly");
+}
+
+#[test]
+fn test_trading_after_resume() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+	alice.transfer(&mut runtime, carol.get_account_id(), to_dai(30).into()).expect("transfer failed couldn't be set");
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	carol.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	alice.place_order(&mut runtime, U64(0), U64(0), U128(1000), U128(50), None).expect("order placement failed unexpectedly");
+
+	root.pause_market(&mut runtime, U64(0)).expect("market pause failed unexpectedly");
+	root.resume_market(&mut runtime, U64(0)).expect("market resume failed unexpectedly");
+
+	carol.place_order(&mut runtime, U64(0), U64(1), U128(1000), U128(50), None).expect("order placement failed unexpectedly");
+	let filled_volume: u128 = alice.get_market_volume(&mut runtime, U64(0)).into();
+	assert_eq!(filled_volume, 100000);
+}
+
+#[test]
+#[should_panic(expected = "only the admin or market creator can pause a market")]
+fn test_pause_market_by_unauthorized_account() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	carol.pause_market(&mut runtime, U64(0)).expect("market pause failed unexpectedly");
+}
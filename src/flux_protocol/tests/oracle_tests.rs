@@ -0,0 +1,117 @@
+use super::*;
+use super::utils::oracle;
+
+fn init_oracle_env(runtime: &mut RuntimeStandalone, root: &ExternalUser, accounts: &Vec<ExternalUser>) {
+	let alice = &accounts[0];
+	alice.transfer(runtime, root.get_account_id(), to_dai(30).into()).expect("transfer failed couldn't be set");
+	alice.set_allowance(runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	root.set_allowance(runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	root.deploy_mock_oracle(runtime).expect("oracle deployment failed unexpectedly");
+	alice.create_market_with_options(runtime, json!({"oracle": oracle()})).expect("market creation failed unexpectedly");
+	runtime.current_block().block_timestamp = market_end_timestamp_ns();
+}
+
+#[test]
+fn test_oracle_resolution() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	init_oracle_env(&mut runtime, &root, &accounts);
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+
+	root.report_oracle_outcome(&mut runtime, U64(0), Some(U64(1))).expect("oracle report failed unexpectedly");
+	/* Anyone can request the resolution */
+	carol.request_resolution(&mut runtime, U64(0)).expect("resolution request failed unexpectedly");
+	assert_eq!(alice.get_markets(&runtime, U64(0), U64(1), None)[0]["resoluted"], true);
+
+	runtime.current_block().block_timestamp = market_end_timestamp_ns() + 43200000000000;
+	alice.finalize_market(&mut runtime, U64(0), None).expect("market finalization failed unexpectedly");
+
+	let validity_bond = to_dai(25) / 100;
+	let claimable_alice: u128 = alice.get_claimable(&mut runtime, U64(0), alice.get_account_id()).into();
+	assert_eq!(claimable_alice, validity_bond);
+}
+
+#[test]
+fn test_oracle_resolution_is_disputable() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	init_oracle_env(&mut runtime, &root, &accounts);
+	let alice = &accounts[0];
+
+	root.report_oracle_outcome(&mut runtime, U64(0), Some(U64(1))).expect("oracle report failed unexpectedly");
+	alice.request_resolution(&mut runtime, U64(0)).expect("resolution request failed unexpectedly");
+
+	/* The dispute bond escalates from the full resolute bond */
+	alice.dispute_market(&mut runtime, U64(0), Some(U64(0)), U128(to_dai(10))).expect("market dispute failed unexpectedly");
+	root.finalize_market(&mut runtime, U64(0), Some(U64(0))).expect("market finalization failed unexpectedly");
+
+	let claimable_alice: u128 = alice.get_claimable(&mut runtime, U64(0), alice.get_account_id()).into();
+	let validity_bond = to_dai(25) / 100;
+	assert_eq!(claimable_alice, to_dai(10) + validity_bond);
+}
+
+#[test]
+#[should_panic(expected = "oracle didn't report an outcome")]
+fn test_request_resolution_before_oracle_report() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	init_oracle_env(&mut runtime, &root, &accounts);
+
+	root.request_resolution(&mut runtime, U64(0)).expect("resolution request failed as expected");
+}
+
+#[test]
+#[should_panic(expected = "oracle markets are resoluted through request_resolution during the reporting window")]
+fn test_public_resolution_of_oracle_market() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	init_oracle_env(&mut runtime, &root, &accounts);
+
+	root.resolute_market(&mut runtime, U64(0), Some(U64(1)), U128(to_dai(5))).expect("market resolution failed as expected");
+}
+
+#[test]
+fn test_final_oracle_report_waives_resolution_fee() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+	alice.transfer(&mut runtime, carol.get_account_id(), to_dai(30).into()).expect("transfer failed couldn't be set");
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	carol.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	root.deploy_mock_oracle(&mut runtime).expect("oracle deployment failed unexpectedly");
+	alice.create_market_with_options(&mut runtime, json!({"oracle": oracle()})).expect("market creation failed unexpectedly");
+	alice.place_order(&mut runtime, U64(0), U64(1), U128(to_dai(1) / 10), U128(50), None).expect("order placement failed unexpectedly");
+	carol.place_order(&mut runtime, U64(0), U64(0), U128(to_dai(1) / 10), U128(50), None).expect("order placement failed unexpectedly");
+
+	runtime.current_block().block_timestamp = market_end_timestamp_ns();
+	root.report_oracle_outcome(&mut runtime, U64(0), Some(U64(1))).expect("oracle report failed unexpectedly");
+	carol.request_resolution(&mut runtime, U64(0)).expect("resolution request failed unexpectedly");
+
+	runtime.current_block().block_timestamp = market_end_timestamp_ns() + 43200000000000;
+	alice.finalize_market(&mut runtime, U64(0), None).expect("market finalization failed unexpectedly");
+
+	/* Nobody staked on the oracle's report so the winnings aren't charged a resolution fee */
+	let validity_bond = to_dai(25) / 100;
+	let claimable_alice: u128 = alice.get_claimable(&mut runtime, U64(0), alice.get_account_id()).into();
+	assert_eq!(claimable_alice, to_dai(10) + validity_bond);
+}
+
+#[test]
+#[should_panic(expected = "protocol is paused")]
+fn test_oracle_resolution_while_paused() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	init_oracle_env(&mut runtime, &root, &accounts);
+
+	root.report_oracle_outcome(&mut runtime, U64(0), Some(U64(1))).expect("oracle report failed unexpectedly");
+	root.pause(&mut runtime, "oracle bug".to_string()).expect("pausing the protocol failed unexpectedly");
+	root.request_resolution(&mut runtime, U64(0)).expect("resolution request failed as expected");
+}
+
+#[test]
+#[should_panic(expected = "invalid oracle account id")]
+fn test_invalid_oracle() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market_with_options(&mut runtime, json!({"oracle": "Not An Account"})).expect("market creation failed as expected");
+}
@@ -0,0 +1,76 @@
+use super::*;
+
+#[test]
+fn test_split_payout_resolution() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	accounts[0].set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	let tx_res = accounts[0].create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	assert_eq!(tx_res.status, ExecutionStatus::SuccessValue(b"0".to_vec()));
+
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+
+	alice.transfer(&mut runtime, carol.get_account_id(), to_dai(30).into()).expect("transfer failed couldn't be set");
+	alice.transfer(&mut runtime, root.get_account_id(), to_dai(30).into()).expect("transfer failed couldn't be set");
+	root.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	carol.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.place_order(&mut runtime, U64(0), U64(0), U128(1000), U128(50), None).expect("order placement failed unexpectedly");
+	carol.place_order(&mut runtime, U64(0), U64(1), U128(1000), U128(50), None).expect("order placement failed unexpectedly");
+
+	runtime.current_block().block_timestamp = market_end_timestamp_ns();
+	root.resolute_market_with_payout(&mut runtime, U64(0), vec![U128(5000), U128(5000)], U128(to_dai(5))).expect("market resolution failed unexpectedly");
+	runtime.current_block().block_timestamp = market_end_timestamp_ns() + 43200000000000;
+	root.finalize_market(&mut runtime, U64(0), None).expect("market finalization failed unexpectedly");
+
+	let claimable_alice: u128 = alice.get_claimable(&mut runtime, U64(0), alice.get_account_id()).into();
+	let claimable_carol: u128 = alice.get_claimable(&mut runtime, U64(0), carol.get_account_id()).into();
+	let claimable_root: u128 = alice.get_claimable(&mut runtime, U64(0), root.get_account_id()).into();
+
+	let validity_bond = to_dai(25) / 100;
+	assert_eq!(claimable_alice, 50000 - 500 + validity_bond);
+	assert_eq!(claimable_carol, 50000 - 500);
+	assert_eq!(claimable_root, to_dai(5) + 1000);
+}
+
+#[test]
+fn test_judge_finalizes_split_payout() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	accounts[0].set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	let tx_res = accounts[0].create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	assert_eq!(tx_res.status, ExecutionStatus::SuccessValue(b"0".to_vec()));
+
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+
+	alice.transfer(&mut runtime, carol.get_account_id(), to_dai(30).into()).expect("transfer failed couldn't be set");
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	carol.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.place_order(&mut runtime, U64(0), U64(0), U128(1000), U128(50), None).expect("order placement failed unexpectedly");
+	carol.place_order(&mut runtime, U64(0), U64(1), U128(1000), U128(50), None).expect("order placement failed unexpectedly");
+
+	runtime.current_block().block_timestamp = market_end_timestamp_ns();
+	alice.resolute_market(&mut runtime, U64(0), Some(U64(0)), U128(to_dai(5))).expect("market resolution failed unexpectedly");
+	carol.dispute_market(&mut runtime, U64(0), Some(U64(1)), U128(to_dai(10))).expect("market dispute failed unexpectedly");
+	root.finalize_market_with_payout(&mut runtime, U64(0), vec![U128(2500), U128(7500)]).expect("market finalization failed unexpectedly");
+
+	let claimable_carol: u128 = alice.get_claimable(&mut runtime, U64(0), carol.get_account_id()).into();
+	assert_eq!(claimable_carol, 75000 - 750);
+}
+
+#[test]
+#[should_panic(expected = "payout numerators need to add up to the payout denominator")]
+fn test_invalid_payout_numerators() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	accounts[0].set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	let tx_res = accounts[0].create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	assert_eq!(tx_res.status, ExecutionStatus::SuccessValue(b"0".to_vec()));
+
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	runtime.current_block().block_timestamp = market_end_timestamp_ns();
+	alice.resolute_market_with_payout(&mut runtime, U64(0), vec![U128(5000), U128(4000)], U128(to_dai(5))).expect("market resolution failed unexpectedly");
+}
@@ -0,0 +1,50 @@
+use super::*;
+
+#[test]
+fn test_default_config() {
+	let (runtime, root, _accounts) = init_runtime_env();
+	let config = root.get_config(&runtime);
+
+	assert_eq!(config["max_fee_percentage"], json!("500"));
+	assert_eq!(config["creation_bond"], json!((to_dai(25) / 100).to_string()));
+	assert_eq!(config["resolution_fee_percentage"], json!("100"));
+	assert_eq!(config["resolute_bond"], json!(to_dai(5).to_string()));
+	assert_eq!(config["resolute_bond_percentage"], json!("0"));
+	assert_eq!(config["min_stake"], json!((to_dai(1) / 100).to_string()));
+	assert_eq!(config["dispute_window"], json!("43200000"));
+	assert_eq!(config["min_dispute_window"], json!("3600000"));
+	assert_eq!(config["max_dispute_window"], json!("604800000"));
+	assert_eq!(config["max_dispute_rounds"], json!("1"));
+	assert_eq!(config["resolution_deadline"], json!("2592000000"));
+	assert_eq!(config["expiry_reward_percentage"], json!("1000"));
+}
+
+#[test]
+fn test_config_changes_dont_affect_live_markets() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.transfer(&mut runtime, root.get_account_id(), to_dai(30).into()).expect("transfer failed couldn't be set");
+	root.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	root.set_config(&mut runtime, json!({"dispute_window": U64(1000), "min_dispute_window": U64(1000), "resolute_bond": U128(to_dai(1))})).expect("updating the config failed unexpectedly");
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+
+	runtime.current_block().block_timestamp = market_end_timestamp_ns();
+	root.resolute_market(&mut runtime, U64(0), Some(U64(1)), U128(to_dai(5))).expect("market resolution failed unexpectedly");
+	root.resolute_market(&mut runtime, U64(1), Some(U64(1)), U128(to_dai(1))).expect("market resolution failed unexpectedly");
+
+	/* The new market uses the 1 second dispute window, the old market still has a 12 hour dispute window */
+	runtime.current_block().block_timestamp = market_end_timestamp_ns() + 1000000000;
+	root.finalize_market(&mut runtime, U64(1), None).expect("market finalization failed unexpectedly");
+	let tx_res = root.finalize_market(&mut runtime, U64(0), None);
+	assert!(tx_res.is_err());
+}
+
+#[test]
+#[should_panic(expected = "only the admin can update the config")]
+fn test_set_config_by_non_admin() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	accounts[0].set_config(&mut runtime, json!({"creation_bond": U128(0)})).expect("updating the config failed unexpectedly");
+}
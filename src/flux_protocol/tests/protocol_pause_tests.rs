@@ -0,0 +1,80 @@
+use super::*;
+
+#[test]
+#[should_panic(expected = "protocol is paused")]
+fn test_create_market_while_paused() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	root.pause(&mut runtime, "matching bug".to_string()).expect("pausing the protocol failed unexpectedly");
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).expect("market creation failed unexpectedly");
+}
+
+#[test]
+fn test_pauser_pause_keeps_exits_available() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	alice.place_order(&mut runtime, U64(0), U64(0), U128(1000), U128(50), None).expect("order placement failed unexpectedly");
+	let balance_before_cancel: u128 = alice.get_balance(&mut runtime, alice.get_account_id()).into();
+
+	root.set_pauser(&mut runtime, carol.get_account_id()).expect("setting the pauser failed unexpectedly");
+	carol.pause(&mut runtime, "matching bug".to_string()).expect("pausing the protocol failed unexpectedly");
+
+	let tx_res = alice.place_order(&mut runtime, U64(0), U64(0), U128(1000), U128(50), None);
+	assert!(tx_res.is_err());
+
+	alice.cancel_order(&mut runtime, U64(0), U64(0), U128(50), U128(0)).expect("order cancelation failed");
+	let balance_after_cancel: u128 = alice.get_balance(&mut runtime, alice.get_account_id()).into();
+	assert_eq!(balance_after_cancel, balance_before_cancel + 50000);
+
+	carol.unpause(&mut runtime).expect("unpausing the protocol failed unexpectedly");
+	alice.place_order(&mut runtime, U64(0), U64(0), U128(1000), U128(50), None).expect("order placement failed unexpectedly");
+}
+
+#[test]
+#[should_panic(expected = "only the owner or pauser can pause or unpause the protocol")]
+fn test_pause_by_unauthorized_account() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	accounts[0].pause(&mut runtime, "no reason".to_string()).expect("pausing the protocol failed unexpectedly");
+}
+
+#[test]
+#[should_panic(expected = "protocol is paused")]
+fn test_finalize_market_while_paused() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	runtime.current_block().block_timestamp = market_end_timestamp_ns();
+	alice.resolute_market(&mut runtime, U64(0), Some(U64(1)), U128(to_dai(5))).expect("market resolution failed unexpectedly");
+
+	/* Disputes are blocked while the protocol is paused, so the resolution can't be finalized either */
+	root.pause(&mut runtime, "matching bug".to_string()).expect("pausing the protocol failed unexpectedly");
+	runtime.current_block().block_timestamp = market_end_timestamp_ns() + 43200000000000;
+	alice.finalize_market(&mut runtime, U64(0), None).expect("market finalization failed as expected");
+}
+
+#[test]
+#[should_panic(expected = "protocol is paused")]
+fn test_market_sell_while_paused() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+	alice.transfer(&mut runtime, carol.get_account_id(), to_dai(30).into()).expect("transfer failed couldn't be set");
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	carol.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	alice.place_order(&mut runtime, U64(0), U64(0), U128(1000), U128(50), None).expect("order placement failed unexpectedly");
+	carol.place_order(&mut runtime, U64(0), U64(1), U128(1000), U128(50), None).expect("order placement failed unexpectedly");
+	alice.place_order(&mut runtime, U64(0), U64(1), U128(1000), U128(40), None).expect("order placement failed unexpectedly");
+
+	root.pause(&mut runtime, "matching bug".to_string()).expect("pausing the protocol failed unexpectedly");
+	alice.dynamic_market_sell(&mut runtime, U64(0), U64(0), U128(1000), U128(1)).expect("market sell failed as expected");
+}
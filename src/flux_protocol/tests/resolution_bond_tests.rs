@@ -0,0 +1,52 @@
+use super::*;
+
+#[test]
+fn test_resolute_bond_scales_with_filled_volume() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+	alice.transfer(&mut runtime, carol.get_account_id(), to_dai(30).into()).expect("transfer failed couldn't be set");
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	carol.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	/* 1 dai floor + 10% of the filled volume */
+	alice.create_market_with_options(&mut runtime, json!({"resolute_bond": U128(to_dai(1)), "resolute_bond_percentage": U128(1000)})).expect("market creation failed unexpectedly");
+
+	/* Fills 10 dai of volume, making the bond 2 dai */
+	alice.place_order(&mut runtime, U64(0), U64(0), U128(to_dai(1) / 10), U128(50), None).expect("order placement failed unexpectedly");
+	carol.place_order(&mut runtime, U64(0), U64(1), U128(to_dai(1) / 10), U128(50), None).expect("order placement failed unexpectedly");
+
+	runtime.current_block().block_timestamp = market_end_timestamp_ns();
+	assert_eq!(alice.get_resolute_bond(&runtime, U64(0)), U128(to_dai(2)));
+	carol.resolute_market(&mut runtime, U64(0), Some(U64(0)), U128(to_dai(15) / 10)).expect("market resolution failed unexpectedly");
+	assert_eq!(alice.get_resolute_bond(&runtime, U64(0)), U128(to_dai(2)));
+	assert_eq!(alice.get_markets(&runtime, U64(0), U64(1), None)[0]["resoluted"], false);
+
+	let balance_before_resolution: u128 = alice.get_balance(&mut runtime, alice.get_account_id()).into();
+	alice.resolute_market(&mut runtime, U64(0), Some(U64(0)), U128(to_dai(1))).expect("market resolution failed unexpectedly");
+	assert_eq!(alice.get_markets(&runtime, U64(0), U64(1), None)[0]["resoluted"], true);
+
+	/* Only half a dai was needed to fill the bond, the rest is refunded */
+	let balance_after_resolution: u128 = alice.get_balance(&mut runtime, alice.get_account_id()).into();
+	assert_eq!(balance_before_resolution - balance_after_resolution, to_dai(5) / 10);
+}
+
+#[test]
+#[should_panic(expected = "resolute_bond has to be within the resolute bond bounds")]
+fn test_resolute_bond_below_floor() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market_with_options(&mut runtime, json!({"resolute_bond": U128(to_dai(1) / 2)})).expect("market creation failed as expected");
+}
+
+#[test]
+#[should_panic(expected = "resolute_bond_percentage too high")]
+fn test_resolute_bond_percentage_too_high() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market_with_options(&mut runtime, json!({"resolute_bond_percentage": U128(1001)})).expect("market creation failed as expected");
+}
@@ -0,0 +1,95 @@
+use super::*;
+
+fn resolution_deadline_ns() -> u64 {
+	/* Default resolution deadline of 30 days after end_time */
+	return market_end_timestamp_ns() + 2592000000000000;
+}
+
+#[test]
+fn test_expired_market_refunds_positions_and_open_orders() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+	alice.transfer(&mut runtime, carol.get_account_id(), to_dai(30).into()).expect("transfer failed couldn't be set");
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	carol.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	carol.place_order(&mut runtime, U64(0), U64(0), U128(1000), U128(50), None).expect("order placement failed unexpectedly");
+	alice.place_order(&mut runtime, U64(0), U64(1), U128(1000), U128(50), None).expect("order placement failed unexpectedly");
+	/* Open order that's never filled */
+	carol.place_order(&mut runtime, U64(0), U64(0), U128(1000), U128(20), None).expect("order placement failed unexpectedly");
+
+	runtime.current_block().block_timestamp = resolution_deadline_ns();
+	carol.finalize_market(&mut runtime, U64(0), None).expect("market finalization failed unexpectedly");
+
+	/* Nobody resoluted the market so there's no resolution fee, carol gets back what she spent plus what was left in her open order */
+	let spent: u128 = 50000;
+	let claimable_carol: u128 = carol.get_claimable(&mut runtime, U64(0), carol.get_account_id()).into();
+	let expiry_reward = to_dai(25) / 100 * 1000 / 10000;
+	assert_eq!(claimable_carol, spent + 20000 + expiry_reward);
+}
+
+#[test]
+fn test_expiry_reward_goes_to_finalizer() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+
+	runtime.current_block().block_timestamp = resolution_deadline_ns();
+	carol.finalize_market(&mut runtime, U64(0), None).expect("market finalization failed unexpectedly");
+
+	/* The creator forfeits the validity bond, 10% of it goes to the account that finalized the expired market */
+	let claimable_alice: u128 = alice.get_claimable(&mut runtime, U64(0), alice.get_account_id()).into();
+	let claimable_carol: u128 = carol.get_claimable(&mut runtime, U64(0), carol.get_account_id()).into();
+	assert_eq!(claimable_alice, 0);
+	assert_eq!(claimable_carol, to_dai(25) / 100 / 10);
+
+	carol.claim_earnings(&mut runtime, U64(0), carol.get_account_id()).expect("claim earnings failed unexpectedly");
+	let carol_balance: u128 = carol.get_balance(&mut runtime, carol.get_account_id()).into();
+	assert_eq!(carol_balance, to_dai(25) / 100 / 10);
+}
+
+#[test]
+#[should_panic(expected = "market has to be resoluted before it can be finalized")]
+fn test_finalize_before_resolution_deadline() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+
+	runtime.current_block().block_timestamp = resolution_deadline_ns() - 1000000;
+	alice.finalize_market(&mut runtime, U64(0), None).expect("market finalization failed as expected");
+}
+
+#[test]
+#[should_panic(expected = "resolution deadline has passed, market can only be finalized as invalid")]
+fn test_resolute_after_resolution_deadline() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+
+	runtime.current_block().block_timestamp = resolution_deadline_ns();
+	alice.resolute_market(&mut runtime, U64(0), Some(U64(1)), U128(to_dai(5))).expect("market resolution failed as expected");
+}
+
+#[test]
+#[should_panic(expected = "protocol is paused")]
+fn test_expire_while_paused() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+
+	/* Nobody can resolute while the protocol is paused, so markets can't expire either */
+	root.pause(&mut runtime, "matching bug".to_string()).expect("pausing the protocol failed unexpectedly");
+	runtime.current_block().block_timestamp = resolution_deadline_ns();
+	alice.finalize_market(&mut runtime, U64(0), None).expect("market finalization failed as expected");
+}
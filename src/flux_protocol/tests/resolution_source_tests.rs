@@ -0,0 +1,111 @@
+use super::*;
+
+fn resolution_source(path: &str, mapping: serde_json::Value, timestamp: u64) -> serde_json::Value {
+	return json!({
+		"endpoint": "https://api.example.com/results",
+		"path": path,
+		"mapping": mapping,
+		"timestamp": timestamp,
+	});
+}
+
+#[test]
+fn test_resolution_source_view() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	let source = resolution_source("$.data.results[0].winner", json!({"match": [["Home", 0], ["Away", 1], ["Draw", 2]]}), market_end_timestamp_ms());
+	alice.create_market_with_options(&mut runtime, json!({
+		"outcomes": U64(3),
+		"outcome_tags": vec!["home", "away", "draw"],
+		"resolution_source": source,
+		"api_source": "",
+	})).expect("market creation failed unexpectedly");
+	alice.create_market_with_options(&mut runtime, json!({})).expect("market creation failed unexpectedly");
+
+	assert_eq!(alice.get_resolution_source(&runtime, U64(0)), source);
+	assert_eq!(alice.get_resolution_source(&runtime, U64(1)), serde_json::Value::Null);
+}
+
+#[test]
+fn test_threshold_resolution_source() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	let source = resolution_source("$.price", json!({"thresholds": [1000, 2000]}), market_end_timestamp_ms() + 3600000);
+	alice.create_market_with_options(&mut runtime, json!({
+		"outcomes": U64(3),
+		"outcome_tags": vec!["low", "mid", "high"],
+		"resolution_source": source,
+		"api_source": "",
+	})).expect("market creation failed unexpectedly");
+
+	assert_eq!(alice.get_resolution_source(&runtime, U64(0))["mapping"], json!({"thresholds": [1000, 2000]}));
+}
+
+#[test]
+#[should_panic(expected = "invalid resolution source path")]
+fn test_invalid_resolution_source_path() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market_with_options(&mut runtime, json!({
+		"resolution_source": resolution_source("$.data[first]", json!("direct"), market_end_timestamp_ms()),
+		"api_source": "",
+	})).expect("market creation failed as expected");
+}
+
+#[test]
+#[should_panic(expected = "resolution source timestamp can't be before end_time")]
+fn test_resolution_source_timestamp_before_end_time() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market_with_options(&mut runtime, json!({
+		"resolution_source": resolution_source("$.winner", json!("direct"), market_end_timestamp_ms() - 1),
+		"api_source": "",
+	})).expect("market creation failed as expected");
+}
+
+#[test]
+#[should_panic(expected = "threshold mapping needs outcomes - 1 thresholds")]
+fn test_threshold_mapping_outcome_mismatch() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market_with_options(&mut runtime, json!({
+		"resolution_source": resolution_source("$.price", json!({"thresholds": [1000, 2000]}), market_end_timestamp_ms()),
+		"api_source": "",
+	})).expect("market creation failed as expected");
+}
+
+#[test]
+#[should_panic(expected = "markets with a resolution_source can't have an api_source")]
+fn test_resolution_source_with_api_source() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market_with_options(&mut runtime, json!({
+		"resolution_source": resolution_source("$.winner", json!("direct"), market_end_timestamp_ms()),
+		"api_source": "{\"endpoint\": \"https://api.example.com/other\", \"path\": \"$.winner\"}",
+	})).expect("market creation failed as expected");
+}
+
+#[test]
+#[should_panic(expected = "duplicate match value")]
+fn test_duplicate_match_value() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.create_market_with_options(&mut runtime, json!({
+		"resolution_source": resolution_source("$.winner", json!({"match": [["Yes", 1], ["YES", 0]]}), market_end_timestamp_ms()),
+		"api_source": "",
+	})).expect("market creation failed as expected");
+}
@@ -0,0 +1,131 @@
+use super::*;
+
+fn init_market_with_staker(runtime: &mut RuntimeStandalone, alice: &ExternalUser, carol: &ExternalUser) {
+	alice.transfer(runtime, carol.get_account_id(), to_dai(30).into()).expect("transfer failed couldn't be set");
+	alice.set_allowance(runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	carol.set_allowance(runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	alice.create_market(runtime, empty_string(), empty_string(), U64(4), outcome_tags(4), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	runtime.current_block().block_timestamp = market_end_timestamp_ns();
+}
+
+#[test]
+fn test_unbonded_resolution_stake_paid_out_on_claim() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+	init_market_with_staker(&mut runtime, alice, carol);
+
+	/* Carol's stake on outcome 1 never reaches the bond */
+	carol.resolute_market(&mut runtime, U64(0), Some(U64(1)), U128(to_dai(2))).expect("market resolution failed unexpectedly");
+	alice.resolute_market(&mut runtime, U64(0), Some(U64(0)), U128(to_dai(5))).expect("market resolution failed unexpectedly");
+
+	runtime.current_block().block_timestamp = market_end_timestamp_ns() + 43200000000000;
+	alice.finalize_market(&mut runtime, U64(0), None).expect("market finalization failed unexpectedly");
+
+	let claimable_carol: u128 = carol.get_claimable(&mut runtime, U64(0), carol.get_account_id()).into();
+	assert_eq!(claimable_carol, to_dai(2));
+
+	carol.claim_earnings(&mut runtime, U64(0), carol.get_account_id()).expect("claim earnings failed unexpectedly");
+	let carol_balance: u128 = carol.get_balance(&mut runtime, carol.get_account_id()).into();
+	assert_eq!(carol_balance, to_dai(30));
+}
+
+#[test]
+fn test_unbonded_dispute_stake_in_last_window_paid_out_on_claim() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+	init_market_with_staker(&mut runtime, alice, carol);
+
+	alice.resolute_market(&mut runtime, U64(0), Some(U64(0)), U128(to_dai(5))).expect("market resolution failed unexpectedly");
+	/* The dispute bond is 10 dai, carol's dispute never fills it */
+	carol.dispute_market(&mut runtime, U64(0), Some(U64(1)), U128(to_dai(4))).expect("market dispute failed unexpectedly");
+
+	runtime.current_block().block_timestamp = market_end_timestamp_ns() + 43200000000000;
+	alice.finalize_market(&mut runtime, U64(0), None).expect("market finalization failed unexpectedly");
+
+	/* The unbonded dispute doesn't count as incorrect stake, alice only gets her bond back */
+	let claimable_alice: u128 = alice.get_claimable(&mut runtime, U64(0), alice.get_account_id()).into();
+	let claimable_carol: u128 = carol.get_claimable(&mut runtime, U64(0), carol.get_account_id()).into();
+	assert_eq!(claimable_alice, to_dai(5) + to_dai(25) / 100);
+	assert_eq!(claimable_carol, to_dai(4));
+}
+
+#[test]
+#[should_panic(expected = "unbonded stake was already paid out by claim_earnings")]
+fn test_withdraw_unbonded_stake_after_claim() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+	init_market_with_staker(&mut runtime, alice, carol);
+
+	carol.resolute_market(&mut runtime, U64(0), Some(U64(1)), U128(to_dai(2))).expect("market resolution failed unexpectedly");
+	alice.resolute_market(&mut runtime, U64(0), Some(U64(0)), U128(to_dai(5))).expect("market resolution failed unexpectedly");
+
+	runtime.current_block().block_timestamp = market_end_timestamp_ns() + 43200000000000;
+	alice.finalize_market(&mut runtime, U64(0), None).expect("market finalization failed unexpectedly");
+
+	carol.claim_earnings(&mut runtime, U64(0), carol.get_account_id()).expect("claim earnings failed unexpectedly");
+	carol.withdraw_dispute_stake(&mut runtime, U64(0), U64(0), Some(U64(1))).expect("dispute stake withdrawal failed as expected");
+}
+
+#[test]
+fn test_withdrawn_stake_isnt_paid_out_twice() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+	init_market_with_staker(&mut runtime, alice, carol);
+
+	carol.resolute_market(&mut runtime, U64(0), Some(U64(1)), U128(to_dai(2))).expect("market resolution failed unexpectedly");
+	alice.resolute_market(&mut runtime, U64(0), Some(U64(0)), U128(to_dai(5))).expect("market resolution failed unexpectedly");
+
+	runtime.current_block().block_timestamp = market_end_timestamp_ns() + 43200000000000;
+	alice.finalize_market(&mut runtime, U64(0), None).expect("market finalization failed unexpectedly");
+
+	carol.withdraw_dispute_stake(&mut runtime, U64(0), U64(0), Some(U64(1))).expect("dispute stake withdrawal failed unexpectedly");
+	let claimable_carol: u128 = carol.get_claimable(&mut runtime, U64(0), carol.get_account_id()).into();
+	let carol_balance: u128 = carol.get_balance(&mut runtime, carol.get_account_id()).into();
+	assert_eq!(claimable_carol, 0);
+	assert_eq!(carol_balance, to_dai(30));
+}
+
+#[test]
+fn test_stake_breakdown() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+	init_market_with_staker(&mut runtime, alice, carol);
+
+	alice.resolute_market(&mut runtime, U64(0), Some(U64(1)), U128(to_dai(2))).expect("market resolution failed unexpectedly");
+	alice.resolute_market(&mut runtime, U64(0), Some(U64(0)), U128(to_dai(5))).expect("market resolution failed unexpectedly");
+	carol.dispute_market(&mut runtime, U64(0), Some(U64(1)), U128(to_dai(4))).expect("market dispute failed unexpectedly");
+
+	let breakdown = alice.get_stake_breakdown(&runtime, U64(0), alice.get_account_id());
+	let windows = breakdown["windows"].as_array().unwrap();
+	assert_eq!(windows.len(), 2);
+	assert_eq!(windows[0]["bonded_payout"], json!([U128(10000), U128(0), U128(0), U128(0)]));
+	assert_eq!(windows[0]["unbonded_stake"], json!(U128(to_dai(2))));
+	assert_eq!(windows[0]["correct"], serde_json::Value::Null);
+	assert_eq!(windows[0]["stakes"].as_array().unwrap().len(), 2);
+	assert_eq!(windows[1]["stakes"].as_array().unwrap().len(), 0);
+	assert_eq!(breakdown["finalized"], json!(false));
+
+	runtime.current_block().block_timestamp = market_end_timestamp_ns() + 43200000000000;
+	alice.finalize_market(&mut runtime, U64(0), None).expect("market finalization failed unexpectedly");
+
+	let breakdown = alice.get_stake_breakdown(&runtime, U64(0), alice.get_account_id());
+	assert_eq!(breakdown["windows"][0]["correct"], json!(true));
+	assert_eq!(breakdown["unbonded_stake"], json!(U128(to_dai(2))));
+	assert_eq!(breakdown["dispute_earnings"], json!(U128(to_dai(5))));
+	assert_eq!(breakdown["entitlement"], json!(U128(to_dai(7))));
+
+	let breakdown = carol.get_stake_breakdown(&runtime, U64(0), carol.get_account_id());
+	assert_eq!(breakdown["windows"][1]["bonded_payout"], serde_json::Value::Null);
+	assert_eq!(breakdown["windows"][1]["stakes"][0]["bonded"], json!(false));
+	assert_eq!(breakdown["entitlement"], json!(U128(to_dai(4))));
+
+	carol.claim_earnings(&mut runtime, U64(0), carol.get_account_id()).expect("claim earnings failed unexpectedly");
+	let breakdown = carol.get_stake_breakdown(&runtime, U64(0), carol.get_account_id());
+	assert_eq!(breakdown["claimed"], json!(true));
+	assert_eq!(breakdown["entitlement"], json!(U128(0)));
+}
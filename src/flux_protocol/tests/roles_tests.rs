@@ -0,0 +1,47 @@
+use super::*;
+
+#[test]
+fn test_two_step_ownership_transfer() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+
+	root.propose_owner(&mut runtime, Some(alice.get_account_id())).expect("proposing a new owner failed unexpectedly");
+	/* Ownership isn't transferred until it's accepted */
+	assert_eq!(root.get_owner(&runtime), root.get_account_id());
+
+	alice.accept_ownership(&mut runtime).expect("accepting ownership failed unexpectedly");
+	assert_eq!(root.get_owner(&runtime), alice.get_account_id());
+
+	/* The other roles stay with the account they were assigned to */
+	assert_eq!(root.get_role(&runtime, "admin"), root.get_account_id());
+	assert_eq!(root.get_judges(&runtime), vec![root.get_account_id()]);
+	assert_eq!(root.get_role(&runtime, "pauser"), root.get_account_id());
+}
+
+#[test]
+#[should_panic(expected = "only the pending owner can accept ownership")]
+fn test_accept_ownership_without_proposal() {
+	let (mut runtime, _root, accounts) = init_runtime_env();
+	accounts[0].accept_ownership(&mut runtime).expect("accepting ownership failed unexpectedly");
+}
+
+#[test]
+#[should_panic(expected = "only the judge can resolute disputed markets")]
+fn test_admin_is_not_judge() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+	alice.transfer(&mut runtime, root.get_account_id(), to_dai(30).into()).expect("transfer failed couldn't be set");
+	root.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	root.set_judges(&mut runtime, vec![carol.get_account_id()], U64(1)).expect("setting the judges failed unexpectedly");
+	assert_eq!(root.get_judges(&runtime), vec![carol.get_account_id()]);
+
+	alice.create_market(&mut runtime, empty_string(), empty_string(), U64(2), outcome_tags(0), categories(), U64(market_end_timestamp_ms()), U128(0), U128(0), "test".to_string()).unwrap();
+	runtime.current_block().block_timestamp = market_end_timestamp_ns();
+	root.resolute_market(&mut runtime, U64(0), Some(U64(1)), U128(to_dai(5))).expect("market resolution failed unexpectedly");
+	alice.dispute_market(&mut runtime, U64(0), Some(U64(0)), U128(to_dai(10))).expect("market dispute failed unexpectedly");
+
+	root.finalize_market(&mut runtime, U64(0), Some(U64(0))).expect("market finalization failed unexpectedly");
+}
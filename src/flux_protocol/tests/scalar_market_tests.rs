@@ -0,0 +1,48 @@
+use super::*;
+
+#[test]
+fn test_scalar_market_payout_calc() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	accounts[0].set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	let tx_res = accounts[0].create_scalar_market(&mut runtime, empty_string(), empty_string(), categories(), U64(market_end_timestamp_ms()), (U64(0), U64(200)), U128(0), U128(0), "test".to_string()).unwrap();
+	assert_eq!(tx_res.status, ExecutionStatus::SuccessValue(b"0".to_vec()));
+
+	let alice = &accounts[0];
+	let carol = &accounts[1];
+
+	alice.transfer(&mut runtime, carol.get_account_id(), to_dai(30).into()).expect("transfer failed couldn't be set");
+	alice.transfer(&mut runtime, root.get_account_id(), to_dai(30).into()).expect("transfer failed couldn't be set");
+	root.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	carol.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	alice.place_order(&mut runtime, U64(0), U64(1), U128(1000), U128(60), None).expect("order placement failed unexpectedly");
+	carol.place_order(&mut runtime, U64(0), U64(0), U128(1000), U128(40), None).expect("order placement failed unexpectedly");
+
+	runtime.current_block().block_timestamp = market_end_timestamp_ns();
+	root.resolute_market(&mut runtime, U64(0), Some(U64(150)), U128(to_dai(5))).expect("market resolution failed unexpectedly");
+	runtime.current_block().block_timestamp = market_end_timestamp_ns() + 43200000000000;
+	root.finalize_market(&mut runtime, U64(0), Some(U64(150))).expect("market finalization failed unexpectedly");
+
+	let claimable_alice: u128 = alice.get_claimable(&mut runtime, U64(0), alice.get_account_id()).into();
+	let claimable_carol: u128 = alice.get_claimable(&mut runtime, U64(0), carol.get_account_id()).into();
+
+	let validity_bond = to_dai(25) / 100;
+	assert_eq!(claimable_alice, 75000 - 750 + validity_bond);
+	assert_eq!(claimable_carol, 25000 - 250);
+}
+
+#[test]
+#[should_panic(expected = "invalid winning outcome")]
+fn test_scalar_resolution_out_of_bounds() {
+	let (mut runtime, root, accounts) = init_runtime_env();
+	accounts[0].set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+	let tx_res = accounts[0].create_scalar_market(&mut runtime, empty_string(), empty_string(), categories(), U64(market_end_timestamp_ms()), (U64(100), U64(200)), U128(0), U128(0), "test".to_string()).unwrap();
+	assert_eq!(tx_res.status, ExecutionStatus::SuccessValue(b"0".to_vec()));
+
+	let alice = &accounts[0];
+	alice.set_allowance(&mut runtime, flux_protocol(), U128(to_dai(30))).expect("allowance couldn't be set");
+
+	runtime.current_block().block_timestamp = market_end_timestamp_ns();
+	alice.resolute_market(&mut runtime, U64(0), Some(U64(201)), U128(to_dai(5))).expect("market resolution failed unexpectedly");
+}
@@ -25,11 +25,16 @@ fn flux_protocol() -> String {
 	return "flux_protocol".to_string();
 }
 
+pub fn oracle() -> String {
+	return "oracle".to_string();
+}
+
 type TxResult = Result<ExecutionOutcome, ExecutionOutcome>;
 
 lazy_static::lazy_static! {
     static ref MARKETS_BYTES: &'static [u8] = include_bytes!("../../../res/flux_protocol.wasm").as_ref();
     static ref FUNGIBLE_TOKEN_BYTES: &'static [u8] = include_bytes!("../../../fungible_token.wasm").as_ref();
+    static ref MOCK_ORACLE_BYTES: &'static [u8] = include_bytes!("../../../res/mock_oracle.wasm").as_ref();
 }
 
 pub fn ntoy(near_amount: Balance) -> Balance {
@@ -98,6 +103,65 @@ impl ExternalUser {
         return ans;
     }
 
+    pub fn deploy_mock_oracle(&self, runtime: &mut RuntimeStandalone) -> TxResult {
+        let tx = self
+        .new_tx(runtime, oracle())
+        .create_account()
+        .transfer(99994508400000000000000000)
+        .deploy_contract(MOCK_ORACLE_BYTES.to_vec())
+        .sign(&self.signer);
+        let res = runtime.resolve_tx(tx).unwrap();
+        runtime.process_all().unwrap();
+        let ans = outcome_into_result(res);
+        return ans;
+    }
+
+    pub fn report_oracle_outcome(
+        &self,
+        runtime: &mut RuntimeStandalone,
+        market_id: U64,
+        winning_outcome: Option<U64>
+    ) -> TxResult {
+        let args = json!({
+            "market_id": market_id,
+            "winning_outcome": winning_outcome,
+        })
+        .to_string()
+        .as_bytes()
+        .to_vec();
+
+        let tx = self
+        .new_tx(runtime, oracle())
+        .function_call("report".into(), args, GAS_STANDARD, 0)
+        .sign(&self.signer);
+        let res = runtime.resolve_tx(tx).unwrap();
+        runtime.process_all().unwrap();
+        let ans = outcome_into_result(res);
+        return ans;
+    }
+
+    pub fn request_resolution(
+        &self,
+        runtime: &mut RuntimeStandalone,
+        market_id: U64
+    ) -> TxResult {
+        let args = json!({
+            "market_id": market_id,
+        })
+        .to_string()
+        .as_bytes()
+        .to_vec();
+
+        let tx = self
+        .new_tx(runtime, flux_protocol())
+        .function_call("request_resolution".into(), args, GAS_STANDARD, 0)
+        .sign(&self.signer);
+        let res = runtime.resolve_tx(tx).unwrap();
+        runtime.process_all().unwrap();
+        let ans = outcome_into_result(res);
+        return ans;
+    }
+
     fn new_tx(&self, runtime: &RuntimeStandalone, receiver_id: AccountId) -> Transaction {
         let nonce = runtime
         .view_access_key(&self.account_id, &self.signer.public_key())
@@ -121,95 +185,688 @@ impl ExternalUser {
     ) -> Result<ExternalUser, ExecutionOutcome> {
         let new_signer = InMemorySigner::from_seed(&new_account_id, KeyType::ED25519, &new_account_id);
         let tx = self
-        .new_tx(runtime, new_account_id.clone())
-        .create_account()
-        .add_key(new_signer.public_key(), AccessKey::full_access())
-        .transfer(amount)
+        .new_tx(runtime, new_account_id.clone())
+        .create_account()
+        .add_key(new_signer.public_key(), AccessKey::full_access())
+        .transfer(amount)
+        .sign(&self.signer);
+
+        let res = runtime.resolve_tx(tx);
+
+        // TODO: this temporary hack, must be rewritten
+        if let Err(err) = res.clone() {
+            if let RuntimeError::InvalidTxError(tx_err) = err {
+                let mut out = ExecutionOutcome::default();
+                out.status = ExecutionStatus::Failure(TxExecutionError::InvalidTxError(tx_err));
+                return Err(out);
+            } else {
+                unreachable!();
+            }
+        } else {
+            outcome_into_result(res.unwrap())?;
+            runtime.process_all().unwrap();
+            Ok(ExternalUser {
+                account_id: new_account_id,
+                signer: new_signer,
+            })
+        }
+	}
+	
+	// flux runtime contract method helpers
+
+	pub fn create_market(
+        &self,
+        runtime: &mut RuntimeStandalone,
+        description: String,
+        extra_info: String,
+        outcomes: U64,
+        outcome_tags: Vec<String>,
+        categories: Vec<String>,
+        end_time: U64,
+        creator_fee_percentage: U128,
+        affiliate_fee_percentage: U128,
+        api_source: String,
+    ) -> TxResult {
+        let args = json!({
+            "description": description,
+            "extra_info": extra_info,
+            "outcomes": outcomes,
+            "outcome_tags": outcome_tags,
+            "categories": categories,
+            "end_time": end_time,
+            "creator_fee_percentage": creator_fee_percentage,
+            "affiliate_fee_percentage": affiliate_fee_percentage,
+            "api_source": api_source,
+        })
+        .to_string()
+        .as_bytes()
+        .to_vec();
+        let tx = self
+        .new_tx(runtime, flux_protocol())
+        .function_call("create_market".into(), args, GAS_STANDARD, 0)
+        .sign(&self.signer);
+        let res = runtime.resolve_tx(tx).expect("resolving tx failed");
+        runtime.process_all().expect("processing tx failed");
+        let ans = outcome_into_result(res);
+        return ans;
+	}
+
+	pub fn create_scalar_market(
+        &self,
+        runtime: &mut RuntimeStandalone,
+        description: String,
+        extra_info: String,
+        categories: Vec<String>,
+        end_time: U64,
+        scalar_bounds: (U64, U64),
+        creator_fee_percentage: U128,
+        affiliate_fee_percentage: U128,
+        api_source: String,
+    ) -> TxResult {
+        let args = json!({
+            "description": description,
+            "extra_info": extra_info,
+            "outcomes": U64(2),
+            "outcome_tags": Vec::<String>::new(),
+            "categories": categories,
+            "end_time": end_time,
+            "creator_fee_percentage": creator_fee_percentage,
+            "affiliate_fee_percentage": affiliate_fee_percentage,
+            "api_source": api_source,
+            "scalar_bounds": scalar_bounds,
+        })
+        .to_string()
+        .as_bytes()
+        .to_vec();
+        let tx = self
+        .new_tx(runtime, flux_protocol())
+        .function_call("create_market".into(), args, GAS_STANDARD, 0)
+        .sign(&self.signer);
+        let res = runtime.resolve_tx(tx).expect("resolving tx failed");
+        runtime.process_all().expect("processing tx failed");
+        let ans = outcome_into_result(res);
+        return ans;
+	}
+
+	pub fn create_conditional_market(
+        &self,
+        runtime: &mut RuntimeStandalone,
+        description: String,
+        extra_info: String,
+        outcomes: U64,
+        outcome_tags: Vec<String>,
+        categories: Vec<String>,
+        end_time: U64,
+        creator_fee_percentage: U128,
+        affiliate_fee_percentage: U128,
+        api_source: String,
+        parent: (U64, U64),
+    ) -> TxResult {
+        let args = json!({
+            "description": description,
+            "extra_info": extra_info,
+            "outcomes": outcomes,
+            "outcome_tags": outcome_tags,
+            "categories": categories,
+            "end_time": end_time,
+            "creator_fee_percentage": creator_fee_percentage,
+            "affiliate_fee_percentage": affiliate_fee_percentage,
+            "api_source": api_source,
+            "parent": parent,
+        })
+        .to_string()
+        .as_bytes()
+        .to_vec();
+        let tx = self
+        .new_tx(runtime, flux_protocol())
+        .function_call("create_market".into(), args, GAS_STANDARD, 0)
+        .sign(&self.signer);
+        let res = runtime.resolve_tx(tx).expect("resolving tx failed");
+        runtime.process_all().expect("processing tx failed");
+        let ans = outcome_into_result(res);
+        return ans;
+	}
+
+	/* Creates a binary market with default parameters, any create_market argument in `options` overrides the default */
+	pub fn create_market_with_options(
+        &self,
+        runtime: &mut RuntimeStandalone,
+        options: serde_json::Value,
+    ) -> TxResult {
+        let mut args = json!({
+            "description": "market".to_string(),
+            "extra_info": "".to_string(),
+            "outcomes": U64(2),
+            "outcome_tags": Vec::<String>::new(),
+            "categories": vec!["test".to_string()],
+            "end_time": U64(market_end_timestamp_ms()),
+            "creator_fee_percentage": U128(0),
+            "affiliate_fee_percentage": U128(0),
+            "api_source": "test".to_string(),
+        });
+        for (key, value) in options.as_object().expect("options need to be an object") {
+            args[key] = value.clone();
+        }
+
+        let tx = self
+        .new_tx(runtime, flux_protocol())
+        .function_call("create_market".into(), args.to_string().as_bytes().to_vec(), GAS_STANDARD, 0)
+        .sign(&self.signer);
+        let res = runtime.resolve_tx(tx).expect("resolving tx failed");
+        runtime.process_all().expect("processing tx failed");
+        let ans = outcome_into_result(res);
+        return ans;
+	}
+
+	pub fn place_order(
+        &self,
+        runtime: &mut RuntimeStandalone,
+        market_id: U64,
+        outcome: U64,
+        shares: U128,
+		price: U128,
+		affiliate_account_id: Option<String>
+    ) -> TxResult {
+        let args = json!({
+            "market_id": market_id,
+            "outcome": outcome,
+            "shares": shares,
+			"price": price,
+			"affiliate_account_id": affiliate_account_id
+        })
+        .to_string()
+        .as_bytes()
+        .to_vec();
+        			
+        let tx = self
+        .new_tx(runtime, flux_protocol())
+        .function_call("place_order".into(), args, 10000000000000000, 0)
+        .sign(&self.signer);
+		
+		let res = runtime.resolve_tx(tx).unwrap();
+        runtime.process_all().unwrap();
+        let ans = outcome_into_result(res);
+        return ans;
+    }
+
+    pub fn propose_owner(
+        &self,
+        runtime: &mut RuntimeStandalone,
+        new_owner: Option<String>
+    ) -> TxResult {
+        let args = json!({
+            "new_owner": new_owner,
+        })
+        .to_string()
+        .as_bytes()
+        .to_vec();
+        
+        let tx = self
+        .new_tx(runtime, flux_protocol())
+        .function_call("propose_owner".into(), args, 10000000000000000, 0)
+        .sign(&self.signer);
+		
+		let res = runtime.resolve_tx(tx).unwrap();
+        runtime.process_all().unwrap();
+        let ans = outcome_into_result(res);
+        return ans;
+    }
+
+    pub fn accept_ownership(
+        &self,
+        runtime: &mut RuntimeStandalone
+    ) -> TxResult {
+        let args = json!({
+        })
+        .to_string()
+        .as_bytes()
+        .to_vec();
+        
+        let tx = self
+        .new_tx(runtime, flux_protocol())
+        .function_call("accept_ownership".into(), args, 10000000000000000, 0)
+        .sign(&self.signer);
+		
+		let res = runtime.resolve_tx(tx).unwrap();
+        runtime.process_all().unwrap();
+        let ans = outcome_into_result(res);
+        return ans;
+    }
+
+    pub fn set_admin(
+        &self,
+        runtime: &mut RuntimeStandalone,
+        admin: String
+    ) -> TxResult {
+        let args = json!({
+            "admin": admin,
+        })
+        .to_string()
+        .as_bytes()
+        .to_vec();
+        
+        let tx = self
+        .new_tx(runtime, flux_protocol())
+        .function_call("set_admin".into(), args, 10000000000000000, 0)
+        .sign(&self.signer);
+		
+		let res = runtime.resolve_tx(tx).unwrap();
+        runtime.process_all().unwrap();
+        let ans = outcome_into_result(res);
+        return ans;
+    }
+
+    pub fn set_judges(
+        &self,
+        runtime: &mut RuntimeStandalone,
+        judges: Vec<String>,
+        threshold: U64
+    ) -> TxResult {
+        let args = json!({
+            "judges": judges,
+            "threshold": threshold,
+        })
+        .to_string()
+        .as_bytes()
+        .to_vec();
+        
+        let tx = self
+        .new_tx(runtime, flux_protocol())
+        .function_call("set_judges".into(), args, 10000000000000000, 0)
+        .sign(&self.signer);
+		
+		let res = runtime.resolve_tx(tx).unwrap();
+        runtime.process_all().unwrap();
+        let ans = outcome_into_result(res);
+        return ans;
+    }
+
+    pub fn set_pauser(
+        &self,
+        runtime: &mut RuntimeStandalone,
+        pauser: String
+    ) -> TxResult {
+        let args = json!({
+            "pauser": pauser,
+        })
+        .to_string()
+        .as_bytes()
+        .to_vec();
+        
+        let tx = self
+        .new_tx(runtime, flux_protocol())
+        .function_call("set_pauser".into(), args, 10000000000000000, 0)
+        .sign(&self.signer);
+		
+		let res = runtime.resolve_tx(tx).unwrap();
+        runtime.process_all().unwrap();
+        let ans = outcome_into_result(res);
+        return ans;
+    }
+
+    pub fn pause(
+        &self,
+        runtime: &mut RuntimeStandalone,
+        reason: String
+    ) -> TxResult {
+        let args = json!({
+            "reason": reason,
+        })
+        .to_string()
+        .as_bytes()
+        .to_vec();
+        
+        let tx = self
+        .new_tx(runtime, flux_protocol())
+        .function_call("pause".into(), args, 10000000000000000, 0)
+        .sign(&self.signer);
+		
+		let res = runtime.resolve_tx(tx).unwrap();
+        runtime.process_all().unwrap();
+        let ans = outcome_into_result(res);
+        return ans;
+    }
+
+    pub fn unpause(
+        &self,
+        runtime: &mut RuntimeStandalone
+    ) -> TxResult {
+        let args = json!({
+        })
+        .to_string()
+        .as_bytes()
+        .to_vec();
+        
+        let tx = self
+        .new_tx(runtime, flux_protocol())
+        .function_call("unpause".into(), args, 10000000000000000, 0)
+        .sign(&self.signer);
+		
+		let res = runtime.resolve_tx(tx).unwrap();
+        runtime.process_all().unwrap();
+        let ans = outcome_into_result(res);
+        return ans;
+    }
+
+    pub fn update_market_metadata(
+        &self,
+        runtime: &mut RuntimeStandalone,
+        market_id: U64,
+        description: Option<String>,
+        extra_info: Option<String>,
+        outcome_tags: Option<Vec<String>>,
+        categories: Option<Vec<String>>
+    ) -> TxResult {
+        let args = json!({
+            "market_id": market_id,
+            "description": description,
+            "extra_info": extra_info,
+            "outcome_tags": outcome_tags,
+            "categories": categories,
+        })
+        .to_string()
+        .as_bytes()
+        .to_vec();
+        
+        let tx = self
+        .new_tx(runtime, flux_protocol())
+        .function_call("update_market_metadata".into(), args, 10000000000000000, 0)
+        .sign(&self.signer);
+		
+		let res = runtime.resolve_tx(tx).unwrap();
+        runtime.process_all().unwrap();
+        let ans = outcome_into_result(res);
+        return ans;
+    }
+
+    pub fn add_market_clarification(
+        &self,
+        runtime: &mut RuntimeStandalone,
+        market_id: U64,
+        clarification: String
+    ) -> TxResult {
+        let args = json!({
+            "market_id": market_id,
+            "clarification": clarification,
+        })
+        .to_string()
+        .as_bytes()
+        .to_vec();
+        
+        let tx = self
+        .new_tx(runtime, flux_protocol())
+        .function_call("add_market_clarification".into(), args, 10000000000000000, 0)
+        .sign(&self.signer);
+		
+		let res = runtime.resolve_tx(tx).unwrap();
+        runtime.process_all().unwrap();
+        let ans = outcome_into_result(res);
+        return ans;
+    }
+
+    pub fn set_creation_policy(
+        &self,
+        runtime: &mut RuntimeStandalone,
+        policy: String
+    ) -> TxResult {
+        let args = json!({
+            "policy": policy,
+        })
+        .to_string()
+        .as_bytes()
+        .to_vec();
+        
+        let tx = self
+        .new_tx(runtime, flux_protocol())
+        .function_call("set_creation_policy".into(), args, 10000000000000000, 0)
+        .sign(&self.signer);
+		
+		let res = runtime.resolve_tx(tx).unwrap();
+        runtime.process_all().unwrap();
+        let ans = outcome_into_result(res);
+        return ans;
+    }
+
+    pub fn set_allowlisted_creator(
+        &self,
+        runtime: &mut RuntimeStandalone,
+        account_id: String,
+        allowed: bool
+    ) -> TxResult {
+        let args = json!({
+            "account_id": account_id,
+            "allowed": allowed,
+        })
+        .to_string()
+        .as_bytes()
+        .to_vec();
+        
+        let tx = self
+        .new_tx(runtime, flux_protocol())
+        .function_call("set_allowlisted_creator".into(), args, 10000000000000000, 0)
+        .sign(&self.signer);
+		
+		let res = runtime.resolve_tx(tx).unwrap();
+        runtime.process_all().unwrap();
+        let ans = outcome_into_result(res);
+        return ans;
+    }
+
+    pub fn propose_market(
+        &self,
+        runtime: &mut RuntimeStandalone,
+        description: String,
+        extra_info: String,
+        outcomes: U64,
+        outcome_tags: Vec<String>,
+        categories: Vec<String>,
+        end_time: U64,
+        creator_fee_percentage: U128,
+        affiliate_fee_percentage: U128,
+        api_source: String,
+    ) -> TxResult {
+        let args = json!({
+            "description": description,
+            "extra_info": extra_info,
+            "outcomes": outcomes,
+            "outcome_tags": outcome_tags,
+            "categories": categories,
+            "end_time": end_time,
+            "creator_fee_percentage": creator_fee_percentage,
+            "affiliate_fee_percentage": affiliate_fee_percentage,
+            "api_source": api_source,
+        })
+        .to_string()
+        .as_bytes()
+        .to_vec();
+        let tx = self
+        .new_tx(runtime, flux_protocol())
+        .function_call("propose_market".into(), args, GAS_STANDARD, 0)
+        .sign(&self.signer);
+        let res = runtime.resolve_tx(tx).expect("resolving tx failed");
+        runtime.process_all().expect("processing tx failed");
+        let ans = outcome_into_result(res);
+        return ans;
+	}
+
+    pub fn approve_market_proposal(
+        &self,
+        runtime: &mut RuntimeStandalone,
+        proposal_id: U64
+    ) -> TxResult {
+        let args = json!({
+            "proposal_id": proposal_id,
+        })
+        .to_string()
+        .as_bytes()
+        .to_vec();
+        
+        let tx = self
+        .new_tx(runtime, flux_protocol())
+        .function_call("approve_market_proposal".into(), args, 10000000000000000, 0)
+        .sign(&self.signer);
+		
+		let res = runtime.resolve_tx(tx).unwrap();
+        runtime.process_all().unwrap();
+        let ans = outcome_into_result(res);
+        return ans;
+    }
+
+    pub fn reject_market_proposal(
+        &self,
+        runtime: &mut RuntimeStandalone,
+        proposal_id: U64,
+        reason: String
+    ) -> TxResult {
+        let args = json!({
+            "proposal_id": proposal_id,
+            "reason": reason,
+        })
+        .to_string()
+        .as_bytes()
+        .to_vec();
+        
+        let tx = self
+        .new_tx(runtime, flux_protocol())
+        .function_call("reject_market_proposal".into(), args, 10000000000000000, 0)
+        .sign(&self.signer);
+		
+		let res = runtime.resolve_tx(tx).unwrap();
+        runtime.process_all().unwrap();
+        let ans = outcome_into_result(res);
+        return ans;
+    }
+
+    pub fn withdraw_market_proposal(
+        &self,
+        runtime: &mut RuntimeStandalone,
+        proposal_id: U64
+    ) -> TxResult {
+        let args = json!({
+            "proposal_id": proposal_id,
+        })
+        .to_string()
+        .as_bytes()
+        .to_vec();
+        
+        let tx = self
+        .new_tx(runtime, flux_protocol())
+        .function_call("withdraw_market_proposal".into(), args, 10000000000000000, 0)
+        .sign(&self.signer);
+		
+		let res = runtime.resolve_tx(tx).unwrap();
+        runtime.process_all().unwrap();
+        let ans = outcome_into_result(res);
+        return ans;
+    }
+
+    pub fn set_config(
+        &self,
+        runtime: &mut RuntimeStandalone,
+        config: serde_json::Value
+    ) -> TxResult {
+        let args = config
+        .to_string()
+        .as_bytes()
+        .to_vec();
+        
+        let tx = self
+        .new_tx(runtime, flux_protocol())
+        .function_call("set_config".into(), args, 10000000000000000, 0)
+        .sign(&self.signer);
+		
+		let res = runtime.resolve_tx(tx).unwrap();
+        runtime.process_all().unwrap();
+        let ans = outcome_into_result(res);
+        return ans;
+    }
+
+    pub fn cancel_market(
+        &self,
+        runtime: &mut RuntimeStandalone,
+        market_id: U64
+    ) -> TxResult {
+        let args = json!({
+            "market_id": market_id,
+        })
+        .to_string()
+        .as_bytes()
+        .to_vec();
+        
+        let tx = self
+        .new_tx(runtime, flux_protocol())
+        .function_call("cancel_market".into(), args, 10000000000000000, 0)
+        .sign(&self.signer);
+		
+		let res = runtime.resolve_tx(tx).unwrap();
+        runtime.process_all().unwrap();
+        let ans = outcome_into_result(res);
+        return ans;
+    }
+
+    pub fn update_market_end_time(
+        &self,
+        runtime: &mut RuntimeStandalone,
+        market_id: U64,
+        end_time: U64
+    ) -> TxResult {
+        let args = json!({
+            "market_id": market_id,
+            "end_time": end_time,
+        })
+        .to_string()
+        .as_bytes()
+        .to_vec();
+        
+        let tx = self
+        .new_tx(runtime, flux_protocol())
+        .function_call("update_market_end_time".into(), args, 10000000000000000, 0)
         .sign(&self.signer);
+		
+		let res = runtime.resolve_tx(tx).unwrap();
+        runtime.process_all().unwrap();
+        let ans = outcome_into_result(res);
+        return ans;
+    }
 
-        let res = runtime.resolve_tx(tx);
-
-        // TODO: this temporary hack, must be rewritten
-        if let Err(err) = res.clone() {
-            if let RuntimeError::InvalidTxError(tx_err) = err {
-                let mut out = ExecutionOutcome::default();
-                out.status = ExecutionStatus::Failure(TxExecutionError::InvalidTxError(tx_err));
-                return Err(out);
-            } else {
-                unreachable!();
-            }
-        } else {
-            outcome_into_result(res.unwrap())?;
-            runtime.process_all().unwrap();
-            Ok(ExternalUser {
-                account_id: new_account_id,
-                signer: new_signer,
-            })
-        }
-	}
-	
-	// flux runtime contract method helpers
-
-	pub fn create_market(
+    pub fn pause_market(
         &self,
         runtime: &mut RuntimeStandalone,
-        description: String,
-        extra_info: String,
-        outcomes: U64,
-        outcome_tags: Vec<String>,
-        categories: Vec<String>,
-        end_time: U64,
-        creator_fee_percentage: U128,
-        affiliate_fee_percentage: U128,
-        api_source: String,
+        market_id: U64
     ) -> TxResult {
         let args = json!({
-            "description": description,
-            "extra_info": extra_info,
-            "outcomes": outcomes,
-            "outcome_tags": outcome_tags,
-            "categories": categories,
-            "end_time": end_time,
-            "creator_fee_percentage": creator_fee_percentage,
-            "affiliate_fee_percentage": affiliate_fee_percentage,
-            "api_source": api_source,
+            "market_id": market_id,
         })
         .to_string()
         .as_bytes()
         .to_vec();
+        
         let tx = self
         .new_tx(runtime, flux_protocol())
-        .function_call("create_market".into(), args, GAS_STANDARD, 0)
+        .function_call("pause_market".into(), args, 10000000000000000, 0)
         .sign(&self.signer);
-        let res = runtime.resolve_tx(tx).expect("resolving tx failed");
-        runtime.process_all().expect("processing tx failed");
+		
+		let res = runtime.resolve_tx(tx).unwrap();
+        runtime.process_all().unwrap();
         let ans = outcome_into_result(res);
         return ans;
-	}
+    }
 
-	pub fn place_order(
+    pub fn resume_market(
         &self,
         runtime: &mut RuntimeStandalone,
-        market_id: U64,
-        outcome: U64,
-        shares: U128,
-		price: U128,
-		affiliate_account_id: Option<String>
+        market_id: U64
     ) -> TxResult {
         let args = json!({
             "market_id": market_id,
-            "outcome": outcome,
-            "shares": shares,
-			"price": price,
-			"affiliate_account_id": affiliate_account_id
         })
         .to_string()
         .as_bytes()
         .to_vec();
-        			
+        
         let tx = self
         .new_tx(runtime, flux_protocol())
-        .function_call("place_order".into(), args, 10000000000000000, 0)
+        .function_call("resume_market".into(), args, 10000000000000000, 0)
         .sign(&self.signer);
 		
 		let res = runtime.resolve_tx(tx).unwrap();
@@ -303,6 +960,34 @@ impl ExternalUser {
         return ans;
     }
     
+    pub fn resolute_market_with_payout(
+        &self,
+        runtime: &mut RuntimeStandalone,
+        market_id: U64,
+        payout_numerators: Vec<U128>,
+        stake: U128
+    ) -> TxResult {
+        let args = json!({
+            "market_id": market_id,
+            "winning_outcome": Option::<U64>::None,
+            "stake": stake,
+            "payout_numerators": payout_numerators,
+        })
+        .to_string()
+        .as_bytes()
+        .to_vec();
+        
+        let tx = self
+        .new_tx(runtime, flux_protocol())
+        .function_call("resolute_market".into(), args, 10000000000000000, 0)
+        .sign(&self.signer);
+		
+		let res = runtime.resolve_tx(tx).unwrap();
+        runtime.process_all().unwrap();
+        let ans = outcome_into_result(res);
+        return ans;
+    }
+    
     pub fn dispute_market(
         &self,
         runtime: &mut RuntimeStandalone,
@@ -355,6 +1040,32 @@ impl ExternalUser {
         return ans;
     }
 
+    pub fn finalize_market_with_payout(
+        &self,
+        runtime: &mut RuntimeStandalone,
+        market_id: U64,
+        payout_numerators: Vec<U128>,
+    ) -> TxResult {
+        let args = json!({
+            "market_id": market_id,
+            "winning_outcome": Option::<U64>::None,
+            "payout_numerators": payout_numerators,
+        })
+        .to_string()
+        .as_bytes()
+        .to_vec();
+        
+        let tx = self
+        .new_tx(runtime, flux_protocol())
+        .function_call("finalize_market".into(), args, 10000000000000000, 0)
+        .sign(&self.signer);
+		
+		let res = runtime.resolve_tx(tx).unwrap();
+        runtime.process_all().unwrap();
+        let ans = outcome_into_result(res);
+        return ans;
+    }
+
     pub fn claim_earnings(
         &self,
         runtime: &mut RuntimeStandalone,
@@ -450,6 +1161,87 @@ impl ExternalUser {
         return market_price;
     }
 
+    pub fn get_role(
+        &self,
+        runtime: &RuntimeStandalone,
+        role: &str
+    ) -> String {
+        let res = runtime.view_method_call(
+            &(flux_protocol()), 
+            role, 
+            json!({})
+        .to_string()
+        .as_bytes())
+        .unwrap()
+        .0;
+
+        let data: serde_json::Value = serde_json::from_slice(res.as_slice()).unwrap();
+        let account_id = serde_json::from_value(data).unwrap();
+
+        return account_id;
+    }
+
+    pub fn get_judges(
+        &self,
+        runtime: &RuntimeStandalone
+    ) -> Vec<String> {
+        let res = runtime.view_method_call(
+            &(flux_protocol()), 
+            "judges", 
+            json!({})
+        .to_string()
+        .as_bytes())
+        .unwrap()
+        .0;
+
+        let data: serde_json::Value = serde_json::from_slice(res.as_slice()).unwrap();
+        let judges = serde_json::from_value(data).unwrap();
+
+        return judges;
+    }
+
+    pub fn get_resolution_source(
+        &self,
+        runtime: &RuntimeStandalone,
+        market_id: U64
+    ) -> serde_json::Value {
+        let res = runtime.view_method_call(
+            &(flux_protocol()), 
+            "get_resolution_source", 
+            json!({
+                "market_id": market_id
+            })
+        .to_string()
+        .as_bytes())
+        .unwrap()
+        .0;
+
+        let data: serde_json::Value = serde_json::from_slice(res.as_slice()).unwrap();
+        return data;
+    }
+
+    pub fn get_stake_breakdown(
+        &self,
+        runtime: &RuntimeStandalone,
+        market_id: U64,
+        account_id: String
+    ) -> serde_json::Value {
+        let res = runtime.view_method_call(
+            &(flux_protocol()), 
+            "get_stake_breakdown", 
+            json!({
+                "market_id": market_id,
+                "account_id": account_id
+            })
+        .to_string()
+        .as_bytes())
+        .unwrap()
+        .0;
+
+        let data: serde_json::Value = serde_json::from_slice(res.as_slice()).unwrap();
+        return data;
+    }
+
     pub fn get_market_price(
         &self, 
         runtime: &RuntimeStandalone, 
@@ -474,6 +1266,94 @@ impl ExternalUser {
         return market_price;
     }
 
+    pub fn get_markets(
+        &self, 
+        runtime: &RuntimeStandalone, 
+        from_index: U64,
+        limit: U64,
+        filter: Option<serde_json::Value>
+    ) -> Vec<serde_json::Value> {
+        let markets_json = runtime
+        .view_method_call(
+            &(flux_protocol()),
+            "get_markets",
+            json!({"from_index": from_index, "limit": limit, "filter": filter})
+            .to_string()
+            .as_bytes(),
+        )
+        .unwrap()
+        .0;
+
+        let data: serde_json::Value = serde_json::from_slice(markets_json.as_slice()).unwrap();
+        let markets = serde_json::from_value(data).unwrap();
+
+        return markets;
+    }
+
+    pub fn get_market_ids_by_category(
+        &self, 
+        runtime: &RuntimeStandalone, 
+        category: String
+    ) -> Vec<U64> {
+        let market_ids_json = runtime
+        .view_method_call(
+            &(flux_protocol()),
+            "get_market_ids_by_category",
+            json!({"category": category})
+            .to_string()
+            .as_bytes(),
+        )
+        .unwrap()
+        .0;
+
+        let data: serde_json::Value = serde_json::from_slice(market_ids_json.as_slice()).unwrap();
+        let market_ids = serde_json::from_value(data).unwrap();
+
+        return market_ids;
+    }
+
+    pub fn get_market_ids_by_creator(
+        &self, 
+        runtime: &RuntimeStandalone, 
+        creator: String
+    ) -> Vec<U64> {
+        let market_ids_json = runtime
+        .view_method_call(
+            &(flux_protocol()),
+            "get_market_ids_by_creator",
+            json!({"creator": creator})
+            .to_string()
+            .as_bytes(),
+        )
+        .unwrap()
+        .0;
+
+        let data: serde_json::Value = serde_json::from_slice(market_ids_json.as_slice()).unwrap();
+        let market_ids = serde_json::from_value(data).unwrap();
+
+        return market_ids;
+    }
+
+    pub fn get_config(
+        &self, 
+        runtime: &RuntimeStandalone
+    ) -> serde_json::Value {
+        let config_json = runtime
+        .view_method_call(
+            &(flux_protocol()),
+            "get_config",
+            json!({})
+            .to_string()
+            .as_bytes(),
+        )
+        .unwrap()
+        .0;
+
+        let config: serde_json::Value = serde_json::from_slice(config_json.as_slice()).unwrap();
+
+        return config;
+    }
+
     pub fn get_outcome_share_balance(
         &self, 
         runtime: &RuntimeStandalone, 
@@ -612,6 +1492,28 @@ impl ExternalUser {
         return res;
     }
 
+    pub fn get_resolute_bond(
+        &self, 
+        runtime: &RuntimeStandalone,  
+        market_id: U64, 
+    ) -> U128 {
+        let bond_json = runtime
+        .view_method_call(
+            &(flux_protocol()),
+            "get_resolute_bond",
+            json!({
+                "market_id": market_id, 
+            })
+            .to_string()
+            .as_bytes(),
+        )
+        .unwrap()
+        .0;
+
+        let bond: U128 = serde_json::from_slice(bond_json.as_slice()).unwrap();
+        return bond;
+    }
+
     pub fn get_liquidity(
         &self, 
         runtime: &RuntimeStandalone,  
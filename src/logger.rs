@@ -9,9 +9,19 @@ use serde_json::json;
 
 use crate::order;
 use crate::market;
+use crate::flux_protocol::MarketProposal;
 
 type Order = order::Order;
 type Market = market::Market;
+type Payout = market::Payout;
+type ProtocolConfig = market::ProtocolConfig;
+
+/**
+ * @notice Convert payout numerators u128 -> U128 so they're logged as strings
+ */
+fn to_loggable_payout(payout: &Payout) -> Vec<U128> {
+	return payout.iter().map(|numerator| U128(*numerator)).collect();
+}
 
 pub fn log_order_filled(order: &Order, shares_to_fill: u128, market_id: u64, outcome: u64) {
 	env::log(
@@ -35,6 +45,22 @@ pub fn log_order_filled(order: &Order, shares_to_fill: u128, market_id: u64, out
 	);
 }
 
+pub fn log_order_refunded(market_id: u64, account_id: String, outcome: u64, spend: u128) {
+	env::log(
+		json!({
+			"type": "order_refunded".to_string(),
+			"params": {
+				"market_id": U64(market_id),
+				"account_id": account_id,
+				"outcome": U64(outcome),
+				"spend": U128(spend),
+			}
+		})
+		.to_string()
+		.as_bytes()
+	);
+}
+
 pub fn log_order_closed(order: &Order, market_id: u64, outcome: u64) {
 	env::log(
 		json!({
@@ -51,6 +77,15 @@ pub fn log_order_closed(order: &Order, market_id: u64, outcome: u64) {
 }
 
 pub fn log_market_creation(market: &Market) {
+	let scalar_bounds = match &market.scalar_bounds {
+		Some(bounds) => Some((U64(bounds.min), U64(bounds.max))),
+		None => None
+	};
+	let parent = match market.parent {
+		Some((parent_id, parent_outcome)) => Some((U64(parent_id), U64(parent_outcome))),
+		None => None
+	};
+
 	env::log(
 		json!({
 			"type": "market_creation".to_string(),
@@ -67,6 +102,15 @@ pub fn log_market_creation(market: &Market) {
 				"resolution_fee_percentage": U128(market.resolution_fee_percentage),
 				"affiliate_fee_percentage": U128(market.affiliate_fee_percentage),
 				"api_source": market.api_source,
+				"resolution_source": market.resolution_source,
+				"dispute_window": U64(market.dispute_window),
+				"resolute_bond": U128(market.resolute_bond),
+				"resolute_bond_percentage": U128(market.resolute_bond_percentage),
+				"designated_reporter": market.designated_reporter,
+				"designated_reporter_window": U64(market.config.designated_reporter_window),
+				"oracle": market.oracle,
+				"scalar_bounds": scalar_bounds,
+				"parent": parent,
 			}
 		})
 		.to_string()
@@ -74,7 +118,7 @@ pub fn log_market_creation(market: &Market) {
 	);
 }
 
-pub fn log_dispute_withdraw(market_id: u64, sender: String, dispute_round: u64, outcome: Option<u64>) {
+pub fn log_dispute_withdraw(market_id: u64, sender: String, dispute_round: u64, payout: &Payout) {
 	env::log(
 		json!({
 			"type": "withdrawn_unbounded_dispute_stake".to_string(),
@@ -82,7 +126,285 @@ pub fn log_dispute_withdraw(market_id: u64, sender: String, dispute_round: u64,
 				"market_id": U64(market_id),
 				"sender": sender,
 				"dispute_round": U64(dispute_round),
-				"outcome": outcome,
+				"payout": to_loggable_payout(payout),
+			}
+		})
+		.to_string()
+		.as_bytes()
+	);
+}
+
+pub fn log_market_updated(market: &Market, clarification: Option<String>) {
+	env::log(
+		json!({
+			"type": "market_updated".to_string(),
+			"params": {
+				"id": U64(market.id),
+				"description": market.description,
+				"extra_info": market.extra_info,
+				"outcome_tags": market.outcome_tags.to_vec(),
+				"categories": market.categories.to_vec(),
+				"clarification": clarification,
+				"update_time": U64(env::block_timestamp() / 1000000),
+			}
+		})
+		.to_string()
+		.as_bytes()
+	);
+}
+
+pub fn log_market_creation_refunded(sender: String, bond: u128) {
+	env::log(
+		json!({
+			"type": "market_creation_refunded".to_string(),
+			"params": {
+				"sender": sender,
+				"bond": U128(bond),
+			}
+		})
+		.to_string()
+		.as_bytes()
+	);
+}
+
+pub fn log_market_proposed(proposal_id: u64, proposal: &MarketProposal) {
+	env::log(
+		json!({
+			"type": "market_proposed".to_string(),
+			"params": {
+				"proposal_id": U64(proposal_id),
+				"proposer": proposal.proposer,
+				"description": proposal.description,
+				"extra_info": proposal.extra_info,
+				"outcomes": U64(proposal.outcomes),
+				"outcome_tags": proposal.outcome_tags,
+				"categories": proposal.categories,
+				"end_time": U64(proposal.end_time),
+				"bond": U128(proposal.bond),
+			}
+		})
+		.to_string()
+		.as_bytes()
+	);
+}
+
+pub fn log_market_proposal_approved(proposal_id: u64, market_id: u64) {
+	env::log(
+		json!({
+			"type": "market_proposal_approved".to_string(),
+			"params": {
+				"proposal_id": U64(proposal_id),
+				"market_id": U64(market_id),
+			}
+		})
+		.to_string()
+		.as_bytes()
+	);
+}
+
+pub fn log_market_proposal_rejected(proposal_id: u64, reason: String) {
+	env::log(
+		json!({
+			"type": "market_proposal_rejected".to_string(),
+			"params": {
+				"proposal_id": U64(proposal_id),
+				"reason": reason,
+			}
+		})
+		.to_string()
+		.as_bytes()
+	);
+}
+
+pub fn log_market_proposal_withdrawn(proposal_id: u64) {
+	env::log(
+		json!({
+			"type": "market_proposal_withdrawn".to_string(),
+			"params": {
+				"proposal_id": U64(proposal_id),
+			}
+		})
+		.to_string()
+		.as_bytes()
+	);
+}
+
+pub fn log_config_updated(config: &ProtocolConfig) {
+	env::log(
+		json!({
+			"type": "config_updated".to_string(),
+			"params": config.to_view()
+		})
+		.to_string()
+		.as_bytes()
+	);
+}
+
+pub fn log_role_updated(role: String, account_id: Option<String>) {
+	env::log(
+		json!({
+			"type": "role_updated".to_string(),
+			"params": {
+				"role": role,
+				"account_id": account_id,
+			}
+		})
+		.to_string()
+		.as_bytes()
+	);
+}
+
+pub fn log_resolution_requested(market_id: u64, sender: String, oracle: String) {
+	env::log(
+		json!({
+			"type": "resolution_requested".to_string(),
+			"params": {
+				"market_id": U64(market_id),
+				"sender": sender,
+				"oracle": oracle,
+			}
+		})
+		.to_string()
+		.as_bytes()
+	);
+}
+
+pub fn log_judges_updated(judges: &Vec<String>, threshold: u64) {
+	env::log(
+		json!({
+			"type": "judges_updated".to_string(),
+			"params": {
+				"judges": judges,
+				"threshold": U64(threshold),
+			}
+		})
+		.to_string()
+		.as_bytes()
+	);
+}
+
+pub fn log_judge_voted(market_id: u64, judge: String, payout: &Payout) {
+	env::log(
+		json!({
+			"type": "judge_voted".to_string(),
+			"params": {
+				"market_id": U64(market_id),
+				"judge": judge,
+				"payout": to_loggable_payout(payout),
+			}
+		})
+		.to_string()
+		.as_bytes()
+	);
+}
+
+pub fn log_judge_vote_tally(market_id: u64, payout: &Payout, votes: u64, threshold: u64) {
+	env::log(
+		json!({
+			"type": "judge_vote_tally".to_string(),
+			"params": {
+				"market_id": U64(market_id),
+				"payout": to_loggable_payout(payout),
+				"votes": U64(votes),
+				"threshold": U64(threshold),
+			}
+		})
+		.to_string()
+		.as_bytes()
+	);
+}
+
+pub fn log_market_paused(market_id: u64, sender: String) {
+	env::log(
+		json!({
+			"type": "market_paused".to_string(),
+			"params": {
+				"market_id": U64(market_id),
+				"sender": sender,
+			}
+		})
+		.to_string()
+		.as_bytes()
+	);
+}
+
+pub fn log_market_resumed(market_id: u64, sender: String) {
+	env::log(
+		json!({
+			"type": "market_resumed".to_string(),
+			"params": {
+				"market_id": U64(market_id),
+				"sender": sender,
+			}
+		})
+		.to_string()
+		.as_bytes()
+	);
+}
+
+pub fn log_protocol_paused(sender: String, reason: String) {
+	env::log(
+		json!({
+			"type": "protocol_paused".to_string(),
+			"params": {
+				"sender": sender,
+				"reason": reason,
+			}
+		})
+		.to_string()
+		.as_bytes()
+	);
+}
+
+pub fn log_protocol_unpaused(sender: String) {
+	env::log(
+		json!({
+			"type": "protocol_unpaused".to_string(),
+			"params": {
+				"sender": sender,
+			}
+		})
+		.to_string()
+		.as_bytes()
+	);
+}
+
+pub fn log_market_end_time_updated(market_id: u64, sender: String, old_end_time: u64, end_time: u64) {
+	env::log(
+		json!({
+			"type": "market_end_time_updated".to_string(),
+			"params": {
+				"market_id": U64(market_id),
+				"sender": sender,
+				"old_end_time": U64(old_end_time),
+				"end_time": U64(end_time),
+			}
+		})
+		.to_string()
+		.as_bytes()
+	);
+}
+
+pub fn log_market_expired(market_id: u64, sender: String) {
+	env::log(
+		json!({
+			"type": "market_expired".to_string(),
+			"params": {
+				"market_id": U64(market_id),
+				"sender": sender,
+			}
+		})
+		.to_string()
+		.as_bytes()
+	);
+}
+
+pub fn log_market_cancelled(market_id: u64) {
+	env::log(
+		json!({
+			"type": "market_cancelled".to_string(),
+			"params": {
+				"market_id": U64(market_id),
 			}
 		})
 		.to_string()
@@ -119,7 +441,7 @@ pub fn log_affiliate_earnings_claimed(sender: String, amount: u128) {
 	);		
 }
 
-pub fn log_market_resoluted(market_id: u64, sender: String, round: u64, staked: u128, outcome: u64) {
+pub fn log_market_resoluted(market_id: u64, sender: String, round: u64, staked: u128, payout: &Payout) {
 	env::log(
 		json!({
 			"type": "market_resoluted".to_string(),
@@ -128,7 +450,7 @@ pub fn log_market_resoluted(market_id: u64, sender: String, round: u64, staked:
 				"sender": sender,
 				"round": U64(round),
 				"staked": U128(staked),
-				"outcome": U64(outcome),
+				"payout": to_loggable_payout(payout),
 			}
 		})
 		.to_string()
@@ -152,7 +474,7 @@ pub fn log_new_resolution_window(market_id: u64, round: u64, bond_size: u128, en
 	);
 }
 
-pub fn log_staked_on_resolution(market_id: u64, sender: String, round: u64, staked: u128, outcome: u64) {
+pub fn log_staked_on_resolution(market_id: u64, sender: String, round: u64, staked: u128, payout: &Payout) {
 	env::log(
 		json!({
 			"type": "staked_on_resolution".to_string(),
@@ -161,7 +483,7 @@ pub fn log_staked_on_resolution(market_id: u64, sender: String, round: u64, stak
 				"sender": sender,
 				"round": U64(round),
 				"staked": U128(staked),
-				"outcome": U64(outcome),
+				"payout": to_loggable_payout(payout),
 			}
 		})
 		.to_string()
@@ -169,7 +491,7 @@ pub fn log_staked_on_resolution(market_id: u64, sender: String, round: u64, stak
 	)
 }
 
-pub fn log_resolution_disputed(market_id: u64, sender: String, round: u64, staked: u128, outcome: u64) {
+pub fn log_resolution_disputed(market_id: u64, sender: String, round: u64, staked: u128, payout: &Payout) {
 	env::log(
 		json!({
 			"type": "resolution_disputed".to_string(),
@@ -178,7 +500,7 @@ pub fn log_resolution_disputed(market_id: u64, sender: String, round: u64, stake
 				"sender": sender,
 				"round": U64(round),
 				"staked": U128(staked),
-				"outcome": U64(outcome),
+				"payout": to_loggable_payout(payout),
 			}
 		})
 		.to_string()
@@ -186,7 +508,7 @@ pub fn log_resolution_disputed(market_id: u64, sender: String, round: u64, stake
 	);
 }
 
-pub fn log_staked_on_dispute(market_id: u64, sender: String, round: u64, staked: u128, outcome: u64) {
+pub fn log_staked_on_dispute(market_id: u64, sender: String, round: u64, staked: u128, payout: &Payout) {
 	env::log(
 		json!({
 			"type": "staked_on_dispute".to_string(),
@@ -195,7 +517,7 @@ pub fn log_staked_on_dispute(market_id: u64, sender: String, round: u64, staked:
 				"sender": sender,
 				"round": U64(round),
 				"staked": U128(staked),
-				"outcome": U64(outcome),
+				"payout": to_loggable_payout(payout),
 			}
 		})
 		.to_string()
@@ -203,13 +525,19 @@ pub fn log_staked_on_dispute(market_id: u64, sender: String, round: u64, staked:
 	)
 }
 
-pub fn log_finalized_market(market_id: u64, winning_outcome: u64) {
+pub fn log_finalized_market(market_id: u64, winning_outcome: Option<u64>, payout: &Payout) {
+	let winning_outcome = match winning_outcome {
+		Some(outcome) => Some(U64(outcome)),
+		None => None
+	};
+
 	env::log(
 		json!({
 			"type": "market_finalized".to_string(),
 			"params": {
 				"market_id": U64(market_id),
-				"winning_outcome": U64(winning_outcome)
+				"winning_outcome": winning_outcome,
+				"payout": to_loggable_payout(payout),
 			}
 		})
 		.to_string()
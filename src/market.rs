@@ -17,13 +17,29 @@ use near_sdk::{
 		BorshSerialize
 	}
 };
-use serde_json::json;
+use serde::{Deserialize, Serialize};
 
 /*** Import orderbook implementation ***/
 use crate::orderbook::Orderbook;
 /*** Import logger methods ***/
 use crate::logger;
 
+/**
+ * @notice A payout holds a numerator per outcome, the numerators of a valid payout add up to PAYOUT_DENOMINATOR
+ * @dev An empty payout represents an invalid resolution
+ */
+pub type Payout = Vec<u128>;
+
+/**
+ * @notice The denominator payout numerators are denominated in, 10000 = 100% of the payout
+ */
+pub const PAYOUT_DENOMINATOR: u128 = 10000;
+
+/**
+ * @notice The max amount of (value, outcome) pairs in a resolution source's match mapping
+ */
+const MAX_MATCH_VALUES: u64 = 64;
+
 /** 
  * @notice Struct of a resolution window, meant to display both resolution and dispute progression and state
  * 
@@ -31,11 +47,289 @@ use crate::logger;
 #[derive(BorshDeserialize, BorshSerialize)]
 pub struct ResolutionWindow {
 	pub round: u64, // 0 = resolution round | >0 = dispute round
-	pub participants_to_outcome_to_stake: UnorderedMap<String, UnorderedMap<u64, u128>>, // Maps participant account_id => outcome => stake_in_outcome
+	pub participants_to_outcome_to_stake: UnorderedMap<String, UnorderedMap<Payout, u128>>, // Maps participant account_id => payout => stake_in_payout
 	pub required_bond_size: u128, // Total bond_size required to move on to next round of escalation
-	pub staked_per_outcome: UnorderedMap<u64, u128>, // Staked per outcome
+	pub staked_per_outcome: UnorderedMap<Payout, u128>, // Staked per payout
 	pub end_time: u64, // Unix timestamp in ms representing when Dispute round is over
-	pub outcome: Option<u64>, // Bonded outcome of this window
+	pub outcome: Option<Payout>, // Bonded payout of this window, None if no payout has been bonded
+}
+
+/**
+ * @notice Lower and upper bound of a scalar market, the value a scalar market resolutes to has to be within [min, max]
+ * @dev Scalar markets always have 2 outcomes where outcome 0 = short and outcome 1 = long
+ */
+#[derive(Serialize, Deserialize, BorshDeserialize, BorshSerialize, Clone)]
+pub struct ScalarBounds {
+	pub min: u64,
+	pub max: u64,
+}
+
+/**
+ * @notice Describes how the value read from a resolution source translates to the winning outcome
+ * @dev Direct = the value is the winning outcome, for scalar markets the value is the resolution value
+ *  Match = (value, outcome) pairs, the value is compared case insensitive, values that don't match resolute the market as invalid
+ *  Thresholds = ascending list of outcomes - 1 thresholds, the winning outcome is the amount of thresholds the value is greater than or equal to
+ */
+#[derive(Serialize, Deserialize, BorshDeserialize, BorshSerialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum OutcomeMapping {
+	Direct,
+	Match(Vec<(String, u64)>),
+	Thresholds(Vec<u64>),
+}
+
+/**
+ * @notice Structured specification of how a market is resoluted, validators and UIs use this to resolute / display the market
+ * @dev The path is a JSON path into the endpoint's response e.g. `$.data.prices[0].usd`
+ */
+#[derive(Serialize, Deserialize, BorshDeserialize, BorshSerialize, Clone, PartialEq)]
+pub struct ResolutionSource {
+	pub endpoint: String, // http(s) endpoint that returns JSON
+	pub path: String, // JSON path to the value in the endpoint's response
+	pub mapping: OutcomeMapping, // How the value maps to the winning outcome
+	pub timestamp: u64, // Unix timestamp in ms of when the value should be read, can't be before the market's end_time
+}
+
+/**
+ * @notice Checks that a JSON path only consists of keys and array indices, e.g. `$.data.prices[0].usd`
+ */
+fn is_valid_json_path(
+	path: &str
+) -> bool {
+	if !path.starts_with('$') {return false;}
+
+	let mut rest = &path[1..];
+	while !rest.is_empty() {
+		if rest.starts_with('.') {
+			/* A key runs until the next `.` or `[` */
+			let key_len = rest[1..].find(|c: char| c == '.' || c == '[').unwrap_or(rest.len() - 1);
+			let key = &rest[1..1 + key_len];
+			if key.is_empty() || key.chars().any(|c| c == ']' || c.is_whitespace()) {return false;}
+			rest = &rest[1 + key_len..];
+		} else if rest.starts_with('[') {
+			let end = match rest.find(']') {
+				Some(end) => end,
+				None => return false
+			};
+			if end < 2 || !rest[1..end].chars().all(|c| c.is_ascii_digit()) {return false;}
+			rest = &rest[end + 1..];
+		} else {
+			return false;
+		}
+	}
+
+	return true;
+}
+
+impl ResolutionSource {
+	/**
+	 * @notice Checks if the resolution source can be used to resolute a market with these parameters
+	 * @dev Panics if the endpoint isn't an http(s) url, the path isn't a valid JSON path, the timestamp is before end_time
+	 *  or the mapping doesn't map to the market's outcomes
+	 */
+	pub fn assert_valid(
+		&self,
+		outcomes: u64,
+		scalar_bounds: &Option<ScalarBounds>,
+		end_time: u64
+	) {
+		assert!(self.endpoint.starts_with("https://") || self.endpoint.starts_with("http://"), "resolution source endpoint has to be an http(s) url");
+		assert!(self.endpoint.chars().count() < 401, "resolution source endpoint can't be more than 400 characters");
+		assert!(!self.endpoint.chars().any(|c| c.is_whitespace()), "resolution source endpoint can't contain whitespace");
+		assert!(self.path.chars().count() < 201, "resolution source path can't be more than 200 characters");
+		assert!(is_valid_json_path(&self.path), "invalid resolution source path");
+		assert!(self.timestamp >= end_time, "resolution source timestamp can't be before end_time");
+
+		match &self.mapping {
+			OutcomeMapping::Direct => {},
+			OutcomeMapping::Match(values) => {
+				assert!(scalar_bounds.is_none(), "scalar markets can only map values directly");
+				assert!(values.len() > 0, "match mapping needs at least 1 value");
+				assert!(values.len() as u64 <= MAX_MATCH_VALUES, "match mapping can't have more than 64 values");
+				for (i, (value, outcome)) in values.iter().enumerate() {
+					assert!(value.chars().count() < 101, "match value can't be more than 100 characters");
+					assert!(*outcome < outcomes, "invalid outcome in match mapping");
+					/* Validators match values ASCII case-insensitively so values that only differ in ASCII case are duplicates */
+					assert!(values[..i].iter().all(|(other_value, _)| !other_value.eq_ignore_ascii_case(value)), "duplicate match value");
+				}
+			},
+			OutcomeMapping::Thresholds(thresholds) => {
+				assert!(scalar_bounds.is_none(), "scalar markets can only map values directly");
+				assert_eq!(thresholds.len() as u64, outcomes - 1, "threshold mapping needs outcomes - 1 thresholds");
+				assert!(thresholds.windows(2).all(|pair| pair[0] < pair[1]), "thresholds have to be ascending");
+			}
+		}
+	}
+}
+
+/**
+ * @notice Protocol parameters, a snapshot of these is stored in each market at creation so config changes never affect live markets
+ */
+#[derive(BorshDeserialize, BorshSerialize, Clone)]
+pub struct ProtocolConfig {
+	pub max_fee_percentage: u128, // Max creator fee percentage denominated in 1e4 where 100 = 1%
+	pub creation_bond: u128, // Validity bond that's escrowed on market creation
+	pub resolution_fee_percentage: u128, // Fee for resolution participants denominated in 1e4 where 100 = 1%
+	pub resolute_bond: u128, // Default floor of the first resolution round's bond, every dispute round doubles the previous bond
+	pub min_resolute_bond: u128, // Min resolute bond floor a market creator can choose
+	pub max_resolute_bond: u128, // Max resolute bond floor a market creator can choose
+	pub resolute_bond_percentage: u128, // Default percentage of the filled volume that's added to the resolute bond floor denominated in 1e4 where 100 = 1%
+	pub max_resolute_bond_percentage: u128, // Max resolute bond percentage a market creator can choose
+	pub min_stake: u128, // Minimum stake per resolution or dispute transaction
+	pub dispute_window: u64, // Default duration of a dispute window in ms
+	pub min_dispute_window: u64, // Min dispute window duration in ms a market creator can choose
+	pub max_dispute_window: u64, // Max dispute window duration in ms a market creator can choose
+	pub max_dispute_rounds: u64, // Amount of bonded dispute rounds after which the judge council decides the outcome
+	pub designated_reporter_window: u64, // Duration in ms after a market's end_time in which only its designated reporter can resolute
+	pub designated_reporter_bond_percentage: u128, // Percentage of the resolute bond the designated reporter has to stake denominated in 1e4 where 100 = 1%
+	pub designated_reporter_slash_percentage: u128, // Percentage of the validity bond that is slashed if the designated reporter didn't report in time, denominated in 1e4
+	pub resolution_deadline: u64, // Duration in ms after a market's end_time after which an unresoluted market can be finalized as invalid by anyone
+	pub expiry_reward_percentage: u128, // Percentage of the validity bond that goes to the account that finalizes an expired market, denominated in 1e4
+}
+
+/**
+ * @notice JSON representation of the protocol config where u128 and u64 values are serialized as strings
+ */
+#[derive(Serialize, Deserialize)]
+pub struct ProtocolConfigView {
+	pub max_fee_percentage: U128,
+	pub creation_bond: U128,
+	pub resolution_fee_percentage: U128,
+	pub resolute_bond: U128,
+	pub min_resolute_bond: U128,
+	pub max_resolute_bond: U128,
+	pub resolute_bond_percentage: U128,
+	pub max_resolute_bond_percentage: U128,
+	pub min_stake: U128,
+	pub dispute_window: U64,
+	pub min_dispute_window: U64,
+	pub max_dispute_window: U64,
+	pub max_dispute_rounds: U64,
+	pub designated_reporter_window: U64,
+	pub designated_reporter_bond_percentage: U128,
+	pub designated_reporter_slash_percentage: U128,
+	pub resolution_deadline: U64,
+	pub expiry_reward_percentage: U128,
+}
+
+impl ProtocolConfig {
+	/**
+	 * @notice Returns the config with u128 and u64 values converted to U128 and U64 so they're serialized as strings
+	 */
+	pub fn to_view(
+		&self
+	) -> ProtocolConfigView {
+		return ProtocolConfigView {
+			max_fee_percentage: U128(self.max_fee_percentage),
+			creation_bond: U128(self.creation_bond),
+			resolution_fee_percentage: U128(self.resolution_fee_percentage),
+			resolute_bond: U128(self.resolute_bond),
+			min_resolute_bond: U128(self.min_resolute_bond),
+			max_resolute_bond: U128(self.max_resolute_bond),
+			resolute_bond_percentage: U128(self.resolute_bond_percentage),
+			max_resolute_bond_percentage: U128(self.max_resolute_bond_percentage),
+			min_stake: U128(self.min_stake),
+			dispute_window: U64(self.dispute_window),
+			min_dispute_window: U64(self.min_dispute_window),
+			max_dispute_window: U64(self.max_dispute_window),
+			max_dispute_rounds: U64(self.max_dispute_rounds),
+			designated_reporter_window: U64(self.designated_reporter_window),
+			designated_reporter_bond_percentage: U128(self.designated_reporter_bond_percentage),
+			designated_reporter_slash_percentage: U128(self.designated_reporter_slash_percentage),
+			resolution_deadline: U64(self.resolution_deadline),
+			expiry_reward_percentage: U128(self.expiry_reward_percentage),
+		};
+	}
+}
+
+/**
+ * @notice Normalizes a category so that e.g. "Sports", " sports" and "sports" are indexed as the same category
+ */
+pub fn normalize_category(
+	category: &str
+) -> String {
+	return category.trim().to_lowercase();
+}
+
+/**
+ * @notice The lifecycle state of a market that markets can be filtered on
+ */
+#[derive(Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum MarketState {
+	Open, // Not resoluted yet
+	Resoluted, // Resoluted and possibly in an escalating dispute round, but not finalized
+	Disputed, // All dispute rounds are exhausted and the judge council has to finalize the market
+	Finalized,
+}
+
+/**
+ * @notice Filter used to query markets, a market needs to match each of the provided fields
+ */
+#[derive(Serialize, Deserialize)]
+pub struct MarketFilter {
+	pub category: Option<String>,
+	pub creator: Option<String>,
+	pub state: Option<MarketState>,
+	pub end_time_from: Option<U64>, // Inclusive lower bound of the market's end_time
+	pub end_time_to: Option<U64>, // Inclusive upper bound of the market's end_time
+}
+
+/**
+ * @notice Summary of a market's state, returned by market listing views
+ */
+#[derive(Serialize, Deserialize)]
+pub struct MarketSummary {
+	pub id: U64,
+	pub description: String,
+	pub extra_info: String,
+	pub creator: String,
+	pub outcomes: U64,
+	pub outcome_tags: Vec<String>,
+	pub categories: Vec<String>,
+	pub end_time: U64,
+	pub resoluted: bool,
+	pub disputed: bool,
+	pub finalized: bool,
+	pub paused: bool,
+	pub prices: Vec<U128>, // Current market price per outcome
+}
+
+/**
+ * @notice An account's stake on a single payout in a resolution window
+ */
+#[derive(Serialize, Deserialize)]
+pub struct PayoutStake {
+	pub payout: Vec<U128>, // Empty if the stake is on an invalid resolution
+	pub stake: U128,
+	pub bonded: bool, // If true the stake is part of the window's bond and is settled by claim_earnings, otherwise it can be withdrawn
+}
+
+/**
+ * @notice An account's participation in a single resolution window
+ */
+#[derive(Serialize, Deserialize)]
+pub struct WindowStakeBreakdown {
+	pub round: U64,
+	pub required_bond_size: U128,
+	pub end_time: U64,
+	pub bonded_payout: Option<Vec<U128>>, // The payout that was bonded in this window, None if no payout reached the bond
+	pub correct: Option<bool>, // Once the market is finalized, whether the bonded payout is the final payout
+	pub stakes: Vec<PayoutStake>,
+	pub unbonded_stake: U128, // The account's stake on payouts that weren't bonded in this window
+}
+
+/**
+ * @notice Breakdown of an account's resolution and dispute stake in a market and what the account is entitled to
+ */
+#[derive(Serialize, Deserialize)]
+pub struct StakeBreakdown {
+	pub windows: Vec<WindowStakeBreakdown>,
+	pub finalized: bool,
+	pub claimed: bool,
+	pub unbonded_stake: U128, // Total unbonded stake, withdrawable through withdraw_dispute_stake or paid out by claim_earnings
+	pub dispute_earnings: U128, // Bonded stake on the final payout plus rewards, only final once the market is finalized
+	pub entitlement: U128, // unbonded_stake + dispute_earnings, 0 once claimed
 }
 
 /** 
@@ -52,10 +346,14 @@ pub struct Market {
 	pub categories: Vector<String>,
 	pub creation_time: u64,
 	pub end_time: u64,
+	pub original_end_time: u64, // The end_time the market was created with, end_time extensions are capped relative to this
 	pub orderbooks: UnorderedMap<u64, Orderbook>,
-	pub winning_outcome: Option<u64>, // If market is finalized and winning_outcome == None, market is deemed invalid
+	pub best_prices: Vec<u128>, // Cached best price per outcome, 0 if the outcome's orderbook has no open orders
+	pub best_prices_sum: u128, // Cached sum of best_prices
+	pub payout: Payout, // If market is finalized and the payout is empty, market is deemed invalid
 	pub resoluted: bool,
-	pub resolute_bond: u128,
+	pub resolute_bond: u128, // Floor of the first resolution round's bond, the bond is fixed once the first stake comes in
+	pub resolute_bond_percentage: u128, // Percentage of the filled volume that's added to the resolute bond floor denominated in 1e4 where 100 = 1%
 	pub filled_volume: u128,
 	pub disputed: bool,
 	pub finalized: bool,
@@ -66,9 +364,23 @@ pub struct Market {
 	pub claimable_if_invalid: UnorderedMap<String, u128>,
 	pub total_feeable_if_invalid: u128,
 	pub api_source: String,
+	pub resolution_source: Option<ResolutionSource>, // If Some validators resolute the market according to this specification
+	pub dispute_window: u64, // Duration of every dispute window of this market in ms
+	pub designated_reporter: Option<String>, // If Some this account has an exclusive window after end_time to resolute the market with a reduced bond
+	pub late_reporter: Option<String>, // If the designated reporter didn't report within its window, the account whose report bonded the resolution round
+	pub expired_by: Option<String>, // If nobody resoluted the market before the resolution deadline, the account that finalized it as invalid
+	pub oracle: Option<String>, // If Some this oracle contract reports the market's outcome through request_resolution, acting as the designated reporter
 	pub resolution_windows: Vector<ResolutionWindow>,
 	pub validity_bond_claimed: bool,
-	pub claimed_earnings: UnorderedMap<String, bool>
+	pub claimed_earnings: UnorderedMap<String, bool>,
+	pub scalar_bounds: Option<ScalarBounds>, // If Some the market is a scalar market
+	pub parent: Option<(u64, u64)>, // (market_id, outcome) condition of a conditional market, if the parent doesn't finalize on this outcome the market is invalid
+	pub invalidated_by_parent: bool,
+	pub parent_finalized_at: Option<u64>, // Time in ms the parent of a conditional market finalized on its condition, the resolution deadline doesn't start before it
+	pub paused: bool, // If true no new orders can be placed or matched, open orders can still be canceled
+	pub config: ProtocolConfig, // Snapshot of the protocol config at the time of creation
+	pub judge_votes: UnorderedMap<String, Payout>, // Maps judge account_id => the payout the judge voted on if the market is disputed
+	pub cancelled: bool, // If true the market was cancelled before it was traded, open orders can be refunded through cancel_order or claim_earnings
 }
 
 impl Market {
@@ -90,6 +402,15 @@ impl Market {
 		resolution_fee_percentage: u128, 
 		affiliate_fee_percentage: u128,
 		api_source: String,
+		resolution_source: Option<ResolutionSource>,
+		dispute_window: u64,
+		resolute_bond: u128,
+		resolute_bond_percentage: u128,
+		designated_reporter: Option<String>,
+		oracle: Option<String>,
+		scalar_bounds: Option<ScalarBounds>,
+		parent: Option<(u64, u64)>,
+		config: ProtocolConfig,
 	) -> Self {
 
 		/* Create new vector store the markets' outcome_tags in */
@@ -121,17 +442,14 @@ impl Market {
 			empty_orderbooks.insert(&i, &Orderbook::new(id, i));
 		}
 
-		/* Declare base value to perform .pow operation on */
-		let base: u128 = 10;
-
 		/* Create empty Vector object that will store all resolution windows */
-		let mut resolution_windows = Vector::new("market:{}:resolution_windows".as_bytes().to_vec());
+		let mut resolution_windows = Vector::new(format!("market:{}:resolution_windows", id).as_bytes().to_vec());
 
 		/* Initiate first resolution window */
 		let base_resolution_window = ResolutionWindow {
 			round: 0,
 			participants_to_outcome_to_stake: UnorderedMap::new(format!("market:{}:participants_to_outcome_to_stake:0", id).as_bytes().to_vec()),
-			required_bond_size: 5 * base.pow(18),
+			required_bond_size: resolute_bond,
 			staked_per_outcome: UnorderedMap::new(format!("market:{}:staked_per_outcome:{}", id, 0).as_bytes().to_vec()), // Staked per outcome
 			end_time: end_time,
 			outcome: None,
@@ -149,10 +467,14 @@ impl Market {
 			categories: categories_vector,
 			creation_time: env::block_timestamp() / 1000000,
 			end_time,
+			original_end_time: end_time,
 			orderbooks: empty_orderbooks,
-			winning_outcome: None,
+			best_prices: vec![0; outcomes as usize],
+			best_prices_sum: 0,
+			payout: vec![],
 			resoluted: false,
-			resolute_bond: 5 * base.pow(18),
+			resolute_bond,
+			resolute_bond_percentage,
 			filled_volume: 0,
 			disputed: false,
 			finalized: false,
@@ -163,9 +485,23 @@ impl Market {
 			claimable_if_invalid: UnorderedMap::new(format!("market:{}:feeable_if_invalid", id).as_bytes().to_vec()),
 			total_feeable_if_invalid: 0,
 			api_source,
+			resolution_source,
+			dispute_window,
+			designated_reporter,
+			late_reporter: None,
+			expired_by: None,
+			oracle,
 			resolution_windows,
 			validity_bond_claimed: false,
 			claimed_earnings: UnorderedMap::new(format!("market:{}:claimed_earnings_for", id).as_bytes().to_vec()),
+			scalar_bounds,
+			parent,
+			invalidated_by_parent: false,
+			parent_finalized_at: None,
+			paused: false,
+			config,
+			cancelled: false,
+			judge_votes: UnorderedMap::new(format!("market:{}:judge_votes", id).as_bytes().to_vec()),
 		};
 	}
 
@@ -203,7 +539,34 @@ impl Market {
 		);
 
 		/* Re-insert the mutated orderbook */
-		self.orderbooks.insert(&outcome, &orderbook);
+		self.insert_orderbook(outcome, &orderbook);
+	}
+
+	/**
+	 * @notice Stores an orderbook and updates the cached best price of its outcome
+	 * @dev Every mutated orderbook should be re-inserted through this method so the cached prices stay in sync
+	 */
+	pub fn insert_orderbook(
+		&mut self,
+		outcome: u64,
+		orderbook: &Orderbook
+	) {
+		let best_price = orderbook.price_data.max().unwrap_or(0);
+		self.best_prices_sum = self.best_prices_sum - self.best_prices[outcome as usize] + best_price;
+		self.best_prices[outcome as usize] = best_price;
+		self.orderbooks.insert(&outcome, orderbook);
+	}
+
+	/**
+	 * @notice Returns the outcomes other than `outcome` that have open orders
+	 */
+	fn get_liquid_outcomes_excluding(
+		&self,
+		outcome: u64
+	) -> Vec<u64> {
+		return (0..self.outcomes)
+		.filter(|orderbook_id| *orderbook_id != outcome && self.best_prices[*orderbook_id as usize] > 0)
+		.collect();
 	}
 
 	/** 
@@ -233,19 +596,13 @@ impl Market {
 			/* Calc the amount of shares to fill at the current price which is the min between the amount spendable / price and depth */
 			let shares_to_fill_at_market_price = cmp::min(spendable / market_price, share_depth.expect("expected there to be share depth"));
 
-			/* Loop through all other orderbooks and fill the shares to fill */
-			for orderbook_id in  0..self.outcomes {
-				if orderbook_id == outcome {continue;}
-
+			/* Loop through all other orderbooks that have open orders and fill the shares to fill */
+			for orderbook_id in self.get_liquid_outcomes_excluding(outcome) {
 				let mut orderbook = self.orderbooks.get(&orderbook_id).expect("orderbook doens't exist where it should");
-
-				/* Check if there are orders in the orderbook */
-				if orderbook.price_data.max().is_some() {
-					/* Fill best orders up to the shares to fill */
-					orderbook.fill_best_orders(shares_to_fill_at_market_price);
-					/* Re-insert the mutaded orderbook instance */
-					self.orderbooks.insert(&orderbook_id, &orderbook); 
-				}
+				/* Fill best orders up to the shares to fill */
+				orderbook.fill_best_orders(shares_to_fill_at_market_price);
+				/* Re-insert the mutaded orderbook instance */
+				self.insert_orderbook(orderbook_id, &orderbook);
 			}
 
 			/* Update tracking variables */
@@ -262,40 +619,99 @@ impl Market {
 
 	/**
 	 * @notice Calculates the market price for a certain outcome
-	 * @dev market_price = 100 - best_price_for_each_other_outcome
+	 * @dev market_price = 100 - best_price_for_each_other_outcome, uses the cached best prices so no orderbooks are loaded
 	 * @return A u128 number representing the market price of the provided outcome
 	 */
 	pub fn get_market_price(
 		&self, 
 		outcome: u64
 	) -> u128 {
-		let mut market_price = 100;
- 		for (orderbook_id, orderbook) in self.orderbooks.iter() {
-			if orderbook_id == outcome {continue};
-			let best_price = orderbook.price_data.max().unwrap_or(0);
-			market_price -= best_price;
+		return 100 - (self.best_prices_sum - self.best_prices[outcome as usize]);
+	}
+
+	/**
+	 * @notice Returns the lifecycle state of the market
+	 */
+	pub fn get_state(
+		&self
+	) -> MarketState {
+		if self.finalized {
+			return MarketState::Finalized;
+		} else if self.disputed {
+			return MarketState::Disputed;
+		} else if self.resoluted {
+			return MarketState::Resoluted;
+		}
+		return MarketState::Open;
+	}
+
+	/**
+	 * @notice Checks if the market matches each of the fields provided in the filter
+	 */
+	pub fn matches_filter(
+		&self,
+		filter: &MarketFilter
+	) -> bool {
+		if let Some(category) = &filter.category {
+			let category = normalize_category(category);
+			if !self.categories.iter().any(|market_category| market_category == category) {return false;}
+		}
+		if let Some(creator) = &filter.creator {
+			if creator != &self.creator {return false;}
+		}
+		if let Some(state) = &filter.state {
+			if state != &self.get_state() {return false;}
 		}
-		return market_price;
+		if let Some(end_time_from) = filter.end_time_from {
+			let end_time_from: u64 = end_time_from.into();
+			if self.end_time < end_time_from {return false;}
+		}
+		if let Some(end_time_to) = filter.end_time_to {
+			let end_time_to: u64 = end_time_to.into();
+			if self.end_time > end_time_to {return false;}
+		}
+		return true;
+	}
+
+	/**
+	 * @notice Summarizes the market's metadata, state and current prices
+	 */
+	pub fn to_summary(
+		&self
+	) -> MarketSummary {
+		return MarketSummary {
+			id: U64(self.id),
+			description: self.description.to_string(),
+			extra_info: self.extra_info.to_string(),
+			creator: self.creator.to_string(),
+			outcomes: U64(self.outcomes),
+			outcome_tags: self.outcome_tags.to_vec(),
+			categories: self.categories.to_vec(),
+			end_time: U64(self.end_time),
+			resoluted: self.resoluted,
+			disputed: self.disputed,
+			finalized: self.finalized,
+			paused: self.paused,
+			prices: (0..self.outcomes).map(|outcome| U128(self.get_market_price(outcome))).collect(),
+		};
 	}
 
 	/**
 	 * @notice Calculates the market price and returns depth at this market price
 	 * @dev market_price = 100 - best_price_for_each_other_outcome
-	 *  depth = min liquidity available at the oposing outcomes' best price
+	 *  depth = min liquidity available at the oposing outcomes' best price, only orderbooks with open orders are loaded
 	 * @return the market price and returns depth at this market price
 	 */
 	pub fn get_market_price_and_min_liquidty(
 		&self, 
 		outcome: u64
 	) -> (u128, Option<u128>) {
-		let mut market_price = 100;
+		let market_price = self.get_market_price(outcome);
 		let mut min_liquidity = None;
 
- 		for (orderbook_id, orderbook) in self.orderbooks.iter() {
-			if orderbook_id == outcome {continue};
-
-			let best_price = orderbook.price_data.max().unwrap_or(0);
-			if best_price == 0 {continue;}
+ 		for orderbook_id in self.get_liquid_outcomes_excluding(outcome) {
+			let orderbook = self.orderbooks.get(&orderbook_id).expect("orderbook doens't exist where it should");
+			let best_price = self.best_prices[orderbook_id as usize];
 			let liq_at_price = orderbook.price_data
 				.get(&best_price)
 				.expect("there should be an entry at best price but there isn't")
@@ -304,8 +720,6 @@ impl Market {
 			if min_liquidity.is_none() || min_liquidity.unwrap() > liq_at_price {
 				min_liquidity = Some(liq_at_price);
 			}
-
-			market_price -= best_price;
 		}
 		return (market_price, min_liquidity);
 	}
@@ -375,13 +789,98 @@ impl Market {
 		orderbook.user_data.insert(&env::predecessor_account_id(), &user_data);
 		
 		/* Re-insert the orderbook */
-		self.orderbooks.insert(&outcome, &orderbook);
+		self.insert_orderbook(outcome, &orderbook);
 		
 		return sell_depth * sell_price;
 	}
 
 	/*** Resolution methods ***/
 
+	/**
+	 * @notice Replaces the market's metadata, only used before the market has been traded
+	 */
+	pub fn update_metadata_internal(
+		&mut self,
+		description: String,
+		extra_info: String,
+		outcome_tags: Vec<String>,
+		categories: Vec<String>
+	) {
+		self.description = description;
+		self.extra_info = extra_info;
+
+		self.outcome_tags.clear();
+		for outcome_tag in &outcome_tags {
+			self.outcome_tags.push(outcome_tag);
+		}
+
+		self.categories.clear();
+		for category in &categories {
+			self.categories.push(category);
+		}
+
+		logger::log_market_updated(self, None);
+	}
+
+	/**
+	 * @notice Checks if any of the market's orderbooks has a resting order
+	 * @dev Answered from the cached best prices so no orderbook has to be loaded, an outcome's best price is only 0 if its orderbook has no price levels
+	 * @return A boolean that's true if there's at least one open order
+	 */
+	pub fn has_open_orders(
+		&self
+	) -> bool {
+		return self.best_prices_sum > 0;
+	}
+
+	/**
+	 * @notice Appends a clarification to extra_info prefixed with the current timestamp in ms, earlier wording is never changed
+	 */
+	pub fn add_clarification_internal(
+		&mut self,
+		clarification: String
+	) {
+		self.extra_info = format!("{}\n[{}] {}", self.extra_info, env::block_timestamp() / 1000000, clarification);
+
+		logger::log_market_updated(self, Some(clarification));
+	}
+
+	/**
+	 * @notice Cancels an untraded market
+	 * @dev Open orders aren't touched, their owners pull the refund through cancel_order or claim_earnings
+	 */
+	pub fn cancel_internal(
+		&mut self
+	) {
+		self.cancelled = true;
+		/* The validity bond is returned on cancellation so it can't be claimed afterwards */
+		self.validity_bond_claimed = true;
+
+		logger::log_market_cancelled(self.id);
+	}
+
+	/**
+	 * @notice Moves the end_time of a market that hasn't been resoluted yet, this closes trading early or postpones resolution
+	 * @dev Panics if there's already stake in the first resolution window
+	 */
+	pub fn update_end_time_internal(
+		&mut self,
+		sender: String,
+		end_time: u64
+	) {
+		let mut resolution_window = self.resolution_windows.get(0).expect("Something went wrong during market creation");
+		assert_eq!(resolution_window.staked_per_outcome.len(), 0, "can't update end_time after resolution started");
+
+		let old_end_time = self.end_time;
+		self.end_time = end_time;
+
+		/* The first resolution window opens when trading closes so its end_time moves along */
+		resolution_window.end_time = end_time;
+		self.resolution_windows.replace(0, &resolution_window);
+
+		logger::log_market_end_time_updated(self.id, sender, old_end_time, end_time);
+	}
+
 	/**
 	 * @notice The resolute method is used to stake on certain outcomes once a market has ended
 	 * @return Returns how many if any of the sender's stake needs to be returned
@@ -389,26 +888,41 @@ impl Market {
 	pub fn resolute_internal(
 		&mut self,
 		sender: String,
-		winning_outcome: Option<u64>, 
+		payout: Payout, 
 		stake: u128
 	) -> u128 {
-		/* Convert option<u64> to a number where None (invalid) = self.outcomes */
-		let outcome_id = self.to_numerical_outcome(winning_outcome);
-
 		/* Get the most recent resolution window */
 		let mut resolution_window = self.resolution_windows.get(self.resolution_windows.len() - 1).expect("Something went wrong during market creation");
 		let mut to_return = 0;
 
-		/* Get how much is currently is staked on the target outcome */
-		let staked_on_outcome = resolution_window.staked_per_outcome.get(&outcome_id).unwrap_or(0);
+		/* The first stake fixes the bond of the resolution round, trading has stopped at this point so the filled volume is final */
+		if resolution_window.participants_to_outcome_to_stake.len() == 0 {
+			/* Oracle reports aren't backed by stake, disputes still escalate from the full resolute bond */
+			resolution_window.required_bond_size = if self.oracle.as_ref() == Some(&sender) {
+				0
+			} else if self.in_designated_reporting_window() {
+				self.get_designated_reporter_bond()
+			} else {
+				self.get_resolute_bond()
+			};
+			logger::log_new_resolution_window(self.id, resolution_window.round, resolution_window.required_bond_size, resolution_window.end_time);
+		}
+
+		/* Get how much is currently is staked on the target payout */
+		let staked_on_outcome = resolution_window.staked_per_outcome.get(&payout).unwrap_or(0);
 
-		/* Check if the total stake on this outcome >= resolution bond if so the stake will be bonded */
-		if stake + staked_on_outcome >= self.resolute_bond {
+		/* Check if the total stake on this payout >= resolution bond if so the stake will be bonded */
+		if stake + staked_on_outcome >= resolution_window.required_bond_size {
 			/* Calculate if anything needs to be returned to the staker */
-			to_return = stake + staked_on_outcome - self.resolute_bond;
-			/* Set winning_outcome - this is not final there could be a dispute */
-			self.winning_outcome = winning_outcome;
+			to_return = stake + staked_on_outcome - resolution_window.required_bond_size;
+			/* Set payout - this is not final there could be a dispute */
+			self.payout = payout.to_vec();
 			self.resoluted = true;
+
+			/* If the designated reporter didn't report in time the creator's validity bond is slashed, also if the designated reporter reports late itself */
+			if self.designated_reporter.is_some() && !self.in_designated_reporting_window() {
+				self.late_reporter = Some(sender.to_string());
+			}
 		} 
 
 		/* Update sender's stake state */
@@ -416,33 +930,34 @@ impl Market {
 		.get(&sender)
 		.unwrap_or(UnorderedMap::new(format!("market:{}:participants_to_outcome_to_stake:{}:{}", self.id, resolution_window.round, sender).as_bytes().to_vec()));
 		let stake_in_outcome = sender_stake_per_outcome
-		.get(&outcome_id)
+		.get(&payout)
 		.unwrap_or(0);
 		let new_stake = stake_in_outcome + stake - to_return;
-		sender_stake_per_outcome.insert(&outcome_id, &new_stake);
+		sender_stake_per_outcome.insert(&payout, &new_stake);
 		resolution_window.participants_to_outcome_to_stake.insert(&sender, &sender_stake_per_outcome);
 
 		/* Update resolution_window's stake state */
-		resolution_window.staked_per_outcome.insert(&outcome_id, &(staked_on_outcome + stake - to_return));
+		resolution_window.staked_per_outcome.insert(&payout, &(staked_on_outcome + stake - to_return));
 		
 		/* If the market is now resoluted open dispute window */
 		if self.resoluted {
-			resolution_window.outcome = winning_outcome;
+			resolution_window.outcome = Some(payout.to_vec());
 			let new_resolution_window = ResolutionWindow {
 				round: resolution_window.round + 1,
 				participants_to_outcome_to_stake: UnorderedMap::new(format!("market:{}:participants_to_outcome_to_stake:{}", self.id, resolution_window.round + 1).as_bytes().to_vec()), // Staked per outcome
-				required_bond_size: resolution_window.required_bond_size * 2,
+				/* Disputes escalate from the full resolute bond, also if the designated reporter resoluted with a reduced bond */
+				required_bond_size: self.get_resolute_bond() * 2,
 				staked_per_outcome: UnorderedMap::new(format!("market:{}:staked_per_outcome:{}", self.id, resolution_window.round + 1).as_bytes().to_vec()), // Staked per outcome
-				end_time: env::block_timestamp() / 1000000 + 43200000, // dispute time is 12 hours for first release
+				end_time: env::block_timestamp() / 1000000 + self.dispute_window,
 				outcome: None,
 			};
 
-			logger::log_market_resoluted(self.id, sender, resolution_window.round, stake - to_return, outcome_id);
+			logger::log_market_resoluted(self.id, sender, resolution_window.round, stake - to_return, &payout);
 			logger::log_new_resolution_window(self.id, new_resolution_window.round, new_resolution_window.required_bond_size, new_resolution_window.end_time);
 			self.resolution_windows.push(&new_resolution_window);
 			
 		}  else {
-			logger::log_staked_on_resolution(self.id, sender, resolution_window.round, stake - to_return, outcome_id);
+			logger::log_staked_on_resolution(self.id, sender, resolution_window.round, stake - to_return, &payout);
 
 		}
 		
@@ -452,33 +967,92 @@ impl Market {
 		return to_return;
 	}
 
+	/**
+	 * @notice Calculates the bond of the resolution round, being the resolute bond floor plus the market's resolute bond percentage of the filled volume
+	 * @return Returns the bond size of the resolution round
+	 */
+	pub fn get_resolute_bond(
+		&self
+	) -> u128 {
+		return self.resolute_bond + self.filled_volume * self.resolute_bond_percentage / 10000;
+	}
+
+	/**
+	 * @notice Checks if the market is in its designated reporter's or oracle's exclusive reporting window
+	 */
+	pub fn in_designated_reporting_window(
+		&self
+	) -> bool {
+		return (self.designated_reporter.is_some() || self.oracle.is_some()) && env::block_timestamp() / 1000000 < self.end_time + self.config.designated_reporter_window;
+	}
+
+	/**
+	 * @notice Calculates the reduced bond the designated reporter has to stake during the reporting window
+	 * @return Returns the designated reporter's bond size, which is at least the min stake
+	 */
+	pub fn get_designated_reporter_bond(
+		&self
+	) -> u128 {
+		return cmp::max(self.config.min_stake, self.get_resolute_bond() * self.config.designated_reporter_bond_percentage / 10000);
+	}
+
+	/**
+	 * @notice Calculates the part of the validity bond that account_id can claim
+	 * @dev If the designated reporter didn't report in time part of the creator's validity bond is slashed, the creator only gets the rest back if the market is valid
+	 *  The slashed part goes to the late reporter, unless that's the designated reporter or the creator in which case it stays in the protocol
+	 *  If the market expired without resolution part of the validity bond goes to the account that finalized it, the rest stays in the protocol like any invalid market's bond
+	 * @return Returns the amount of the validity bond that's claimable by account_id
+	 */
+	pub fn get_validity_bond_claimable(
+		&self,
+		account_id: &String
+	) -> u128 {
+		let slash = match self.late_reporter {
+			Some(_) => self.config.creation_bond * self.config.designated_reporter_slash_percentage / 10000,
+			None => 0
+		};
+
+		let mut claimable = 0;
+		/* The creator can't win back the slashed part of their own validity bond by reporting late themselves */
+		if self.late_reporter.as_ref() == Some(account_id) && self.designated_reporter.as_ref() != Some(account_id) && account_id != &self.creator {
+			claimable += slash;
+		}
+		/* The creator can't collect the expiry reward by letting their own market expire */
+		if self.expired_by.as_ref() == Some(account_id) && account_id != &self.creator {
+			claimable += self.config.creation_bond * self.config.expiry_reward_percentage / 10000;
+		}
+		if account_id == &self.creator && self.validity_bond_claimed == false && (self.is_valid() || self.invalidated_by_parent) {
+			claimable += self.config.creation_bond - slash;
+		}
+
+		return claimable;
+	}
+
 	/**
 	 * @notice The dispute method is to correct incorrect resolutions posted by the initial resolutor(s)
+	 * @dev Every bonded dispute opens a new dispute window with a doubled bond until `max_dispute_rounds` is reached,
+	 *  after that the market is marked as disputed and has to be finalized by the judge council
 	 * @return Returns how many if any of the sender's stake needs to be returned
 	 */
 	pub fn dispute_internal(
 		&mut self, 
 		sender: String,
-		winning_outcome: Option<u64>,
+		payout: Payout,
 		stake: u128
 	) -> u128 {
-		/* Convert option<u64> to a number where None (invalid) = self.outcomes */
-		let outcome_id = self.to_numerical_outcome(winning_outcome);
-		
 		/* Get the most recent resolution window */
 		let mut resolution_window = self.resolution_windows.get(self.resolution_windows.len() - 1).expect("Something went wrong during market creation");
 		let mut to_return = 0;
 		let full_bond_size = resolution_window.required_bond_size;
 		let mut bond_filled = false;
-		let staked_on_outcome = resolution_window.staked_per_outcome.get(&outcome_id).unwrap_or(0);
+		let staked_on_outcome = resolution_window.staked_per_outcome.get(&payout).unwrap_or(0);
 
 		/* Check if this stake adds up to an amount >= the bond_size if so dispute will be bonded */
 		if staked_on_outcome + stake >= full_bond_size  {
 			bond_filled = true;
 			to_return = staked_on_outcome + stake - full_bond_size;
-			self.disputed = true;
-			/* Set winning_outcome to current outcome - will be finalized by Judge */
-			self.winning_outcome = winning_outcome;
+			/* Set payout to current payout - this is final if the next dispute window closes undisputed, unless the judge council has to decide */
+			self.payout = payout.to_vec();
 		}
 
 		/* Add stake to user's stake state */
@@ -486,39 +1060,44 @@ impl Market {
 		.get(&sender)
 		.unwrap_or(UnorderedMap::new(format!("market:{}:participants_to_outcome_to_stake:{}:{}", self.id, resolution_window.round, sender).as_bytes().to_vec()));
 		let stake_in_outcome = sender_stake_per_outcome
-		.get(&outcome_id)
+		.get(&payout)
 		.unwrap_or(0);
 		let new_stake = stake_in_outcome + stake - to_return;
-		sender_stake_per_outcome.insert(&outcome_id, &new_stake);
+		sender_stake_per_outcome.insert(&payout, &new_stake);
 		resolution_window.participants_to_outcome_to_stake.insert(&sender, &sender_stake_per_outcome);
 
 		/* Add stake to the window's stake state */
-		resolution_window.staked_per_outcome.insert(&outcome_id, &(staked_on_outcome + stake - to_return));
+		resolution_window.staked_per_outcome.insert(&payout, &(staked_on_outcome + stake - to_return));
 
 		
 		// Check if this order fills the bond - if so open a new resolution window
 		if bond_filled {
 			// Set last winning outcome
-			resolution_window.outcome = winning_outcome;
+			resolution_window.outcome = Some(payout.to_vec());
 
-			let staked_on_outcome = resolution_window.staked_per_outcome.get(&outcome_id).expect("This can't be None");
+			let staked_on_outcome = resolution_window.staked_per_outcome.get(&payout).expect("This can't be None");
 			assert_eq!(staked_on_outcome, full_bond_size, "the total staked on outcome needs to equal full bond size if we get here");
 
-			let next_resolution_window = ResolutionWindow{
-				round: resolution_window.round + 1,
-				participants_to_outcome_to_stake: UnorderedMap::new(format!("market:{}:participants_to_outcome_to_stake:{}", self.id, resolution_window.round + 1).as_bytes().to_vec()), // Staked per outcome
-				required_bond_size: resolution_window.required_bond_size * 2,
-				staked_per_outcome: UnorderedMap::new(format!("market:{}:staked_per_outcome:{}", self.id, resolution_window.round + 1).as_bytes().to_vec()), // Staked per outcome
-				end_time: env::block_timestamp() / 1000000 + 43200000,
-				outcome: None,
-			};
+			logger::log_resolution_disputed(self.id, sender, resolution_window.round, stake - to_return, &payout);
 
-			logger::log_resolution_disputed(self.id, sender, resolution_window.round, stake - to_return, outcome_id);
-			logger::log_new_resolution_window(self.id, next_resolution_window.round, next_resolution_window.required_bond_size, next_resolution_window.end_time);
+			/* Once the max amount of dispute rounds is reached the judge council decides, otherwise the dispute escalates to a new round */
+			if resolution_window.round >= self.config.max_dispute_rounds {
+				self.disputed = true;
+			} else {
+				let next_resolution_window = ResolutionWindow{
+					round: resolution_window.round + 1,
+					participants_to_outcome_to_stake: UnorderedMap::new(format!("market:{}:participants_to_outcome_to_stake:{}", self.id, resolution_window.round + 1).as_bytes().to_vec()), // Staked per outcome
+					required_bond_size: resolution_window.required_bond_size * 2,
+					staked_per_outcome: UnorderedMap::new(format!("market:{}:staked_per_outcome:{}", self.id, resolution_window.round + 1).as_bytes().to_vec()), // Staked per outcome
+					end_time: env::block_timestamp() / 1000000 + self.dispute_window,
+					outcome: None,
+				};
 
-			self.resolution_windows.push(&next_resolution_window);
+				logger::log_new_resolution_window(self.id, next_resolution_window.round, next_resolution_window.required_bond_size, next_resolution_window.end_time);
+				self.resolution_windows.push(&next_resolution_window);
+			}
 		} else {
-			logger::log_staked_on_dispute(self.id, sender, resolution_window.round, stake - to_return, outcome_id);
+			logger::log_staked_on_dispute(self.id, sender, resolution_window.round, stake - to_return, &payout);
 		}
 
 		// Re-insert the resolution window
@@ -527,23 +1106,82 @@ impl Market {
 		return to_return;
 	}
 
+	/**
+	 * @notice Records a judge's vote on the payout of a disputed market
+	 * @dev Panics if the judge already voted
+	 */
+	pub fn judge_vote_internal(
+		&mut self,
+		judge: String,
+		payout: &Payout
+	) {
+		assert!(self.judge_votes.get(&judge).is_none(), "judge has already voted");
+		self.judge_votes.insert(&judge, payout);
+		logger::log_judge_voted(self.id, judge, payout);
+	}
+
 	/**
 	 * @notice Finalize the market outcome, after which earnings can be claimed by all participants
 	 */
 	pub fn finalize_internal(
 		&mut self, 
-		winning_outcome: Option<u64>
+		payout: Payout
 	) {
-		// If the market was disputed the sender of this tx will be the judge and the judge will provide the final verdict being the definite outcome
+		// If the market was disputed the sender of this tx will be the judge and the judge will provide the final verdict being the definite payout
+		// otherwise the payout is the outcome of the last bonded resolution window
 	    if self.disputed {
-            self.winning_outcome = winning_outcome;
+            self.payout = payout;
 		}
 
-		logger::log_finalized_market(self.id, self.to_numerical_outcome(self.winning_outcome));
+		logger::log_finalized_market(self.id, self.get_winning_outcome(), &self.payout);
 		
 	    self.finalized = true;
 	}
 
+	/**
+	 * @notice Finalizes a conditional market as invalid because its parent market didn't finalize on the market's condition
+	 * @dev All positions are refunded through the invalid path of get_claimable_internal
+	 */
+	pub fn invalidate_by_parent_internal(
+		&mut self
+	) {
+		self.payout = vec![];
+		self.resoluted = true;
+		self.finalized = true;
+		self.invalidated_by_parent = true;
+
+		logger::log_finalized_market(self.id, None, &self.payout);
+	}
+
+	/**
+	 * @notice Finalizes a market as invalid because nobody resoluted it before the resolution deadline
+	 * @dev All positions and open orders are refunded through the invalid path of get_claimable_internal
+	 */
+	pub fn expire_internal(
+		&mut self,
+		sender: String
+	) {
+		self.payout = vec![];
+		self.resoluted = true;
+		self.finalized = true;
+		self.expired_by = Some(sender.to_string());
+
+		logger::log_market_expired(self.id, sender);
+		logger::log_finalized_market(self.id, None, &self.payout);
+	}
+
+	/**
+	 * @notice Checks if the market's resolution deadline has passed without the market being resoluted
+	 * @dev A conditional market can only be resoluted once its parent finalized, so its deadline starts at the later of end_time and the parent's finalization
+	 */
+	pub fn is_past_resolution_deadline(
+		&self
+	) -> bool {
+		if self.parent.is_some() && self.parent_finalized_at.is_none() {return false}
+		let deadline_start = cmp::max(self.end_time, self.parent_finalized_at.unwrap_or(0));
+		return !self.resoluted && env::block_timestamp() / 1000000 >= deadline_start + self.config.resolution_deadline;
+	}
+
 	/*** After finalization ***/
 
 	/**
@@ -554,51 +1192,32 @@ impl Market {
 		&self, 
 		account_id: String
 	) -> (u128, u128, u128) {
-		let invalid = self.winning_outcome.is_none();
+		let invalid = !self.is_valid();
 		let mut winnings = 0;
 		let mut in_open_orders = 0;
 
-		if invalid {
-			/* Loop through all orderbooks */
-			for (_, orderbook) in self.orderbooks.iter() {
-				/* Check if the user has any paritipation in this outcome else continue to next outcome */
-				let user_data = match orderbook.user_data.get(&account_id) {
-					Some(user) => user,
-					None => continue
-				};
+		/* Loop through all orderbooks */
+		for (orderbook_id, orderbook) in self.orderbooks.iter() {
+			/* Check if the user has any paritipation in this outcome else continue to next outcome */
+			let user_data = match orderbook.user_data.get(&account_id) {
+				Some(user) => user,
+				None => continue
+			};
+
+			/* Calculate and add money in open orders */
+			in_open_orders += user_data.to_spend - user_data.spent;
 
-				/* Calculate and add money in open orders */
-				in_open_orders += user_data.to_spend - user_data.spent;
+			if invalid {
 				/* Treat filled volume as winnings */
 				winnings += user_data.spent;
+			} else {
+				/* Calculate user winnings: shares_owned * 100 * payout_numerator / payout_denominator */
+				winnings += user_data.balance * 100 * self.payout[orderbook_id as usize] / PAYOUT_DENOMINATOR;
 			}
-		} else {
-			/* Loop through all orderbooks */
-			for (_, orderbook) in self.orderbooks.iter() {
-				/* Check if the user has any paritipation in this outcome else continue to next outcome */
-				let user_data = match orderbook.user_data.get(&account_id) {
-					Some(user) => user,
-					None => continue
-				};
-				/* Calculate and increment in_open_orders with open orders for each outcome */
-				in_open_orders += user_data.to_spend - user_data.spent;
-			}
-
-			/* Get the orderbook of the winning outcome */
-			let winning_orderbook = self.orderbooks.get(&self.to_numerical_outcome(self.winning_outcome)).unwrap();
-
-			/* Check if the user traded in the winning_outcome */
-			let winning_value = match winning_orderbook.user_data.get(&account_id) {
-				Some(user) => user.balance * 100, // Calculate user winnings: shares_owned * 100
-				None => 0
-			};
-
-			/* Set winnings to the amount of participation */
-			winnings = winning_value;
 		}
 
-		/* Calculate governance earnings */ 
-		let governance_earnings = self.get_dispute_earnings(account_id.to_string());
+		/* Calculate governance earnings, stake that was never bonded is returned as well */ 
+		let governance_earnings = self.get_dispute_earnings(account_id.to_string()) + self.get_total_unbonded_stake(&account_id);
 
 		return (winnings, in_open_orders, governance_earnings);
 	}
@@ -613,25 +1232,22 @@ impl Market {
 	pub fn withdraw_resolution_stake_internal(
 		&mut self,
 		round: u64,
-		outcome: Option<u64>
+		payout: Payout
 	) -> u128{
-		/* Convert option<u64> to a number where None (invalid) = self.outcomes */
-		let outcome_id = self.to_numerical_outcome(outcome);
-
 		/* Get the target resolution window a user wants to withdraw their stake from */
 		let mut resolution_window = self.resolution_windows.get(round).expect("dispute round doesn't exist");
-		assert_ne!(outcome, resolution_window.outcome, "you cant cancel dispute stake for bonded outcome");
+		assert!(resolution_window.outcome.as_ref() != Some(&payout), "you cant cancel dispute stake for bonded outcome");
 		let mut sender_particiaption = resolution_window.participants_to_outcome_to_stake.get(&env::predecessor_account_id()).expect("user didn't paritcipate in this dispute round");
-		let to_return = sender_particiaption.get(&outcome_id).expect("sender didn't pariticipate in this outcome resolution");
+		let to_return = sender_particiaption.get(&payout).expect("sender didn't pariticipate in this outcome resolution");
 		assert!(to_return > 0, "Can't withdraw 0");
 
 		/* Set senders stake to 0 and re-insert to resolution window */
-		sender_particiaption.insert(&outcome_id, &0);
+		sender_particiaption.insert(&payout, &0);
 		resolution_window.participants_to_outcome_to_stake.insert(&env::predecessor_account_id(), &sender_particiaption);
 
-		let staked_on_outcome = resolution_window.staked_per_outcome.get(&outcome_id).expect("Unexpecter error during withdraw resolution");
+		let staked_on_outcome = resolution_window.staked_per_outcome.get(&payout).expect("Unexpecter error during withdraw resolution");
 		/* Decrement total stake by to_return */
-		resolution_window.staked_per_outcome.insert(&outcome_id, &(staked_on_outcome - to_return));
+		resolution_window.staked_per_outcome.insert(&payout, &(staked_on_outcome - to_return));
 		
 		/* Re-insert updated resolution window */
 		self.resolution_windows.replace(resolution_window.round, &resolution_window);
@@ -639,8 +1255,85 @@ impl Market {
 		return to_return;
 	}
 
+	/**
+	 * @notice Calculates the stake account_id has on payouts that weren't bonded in a resolution window
+	 */
+	fn get_unbonded_stake(
+		&self,
+		window: &ResolutionWindow,
+		account_id: &String
+	) -> u128 {
+		return match window.participants_to_outcome_to_stake.get(account_id) {
+			Some(participation) => participation.iter().filter(|(payout, _)| window.outcome.as_ref() != Some(payout)).map(|(_, stake)| stake).sum(),
+			None => 0
+		};
+	}
+
+	/**
+	 * @notice Calculates the stake account_id has on payouts that weren't bonded over all resolution windows, including the last open window
+	 * @dev Unbonded stake is never at risk, it can be withdrawn at any time and is paid out by claim_earnings once the market is finalized
+	 */
+	pub fn get_total_unbonded_stake(
+		&self,
+		account_id: &String
+	) -> u128 {
+		return self.resolution_windows.iter().map(|window| self.get_unbonded_stake(&window, account_id)).sum();
+	}
+
+	/**
+	 * @notice Breaks down the resolution and dispute stake of account_id per resolution window
+	 * @return Returns the stake per window and payout, together with the amount account_id is entitled to
+	 */
+	pub fn get_stake_breakdown(
+		&self,
+		account_id: &String
+	) -> StakeBreakdown {
+		let mut windows = vec![];
+
+		for window in self.resolution_windows.iter() {
+			let stakes: Vec<PayoutStake> = match window.participants_to_outcome_to_stake.get(account_id) {
+				Some(participation) => participation.iter()
+				.filter(|(_, stake)| *stake > 0)
+				.map(|(payout, stake)| PayoutStake {
+					bonded: window.outcome.as_ref() == Some(&payout),
+					payout: payout.into_iter().map(|numerator| U128(numerator)).collect(),
+					stake: U128(stake),
+				})
+				.collect(),
+				None => vec![]
+			};
+
+			windows.push(WindowStakeBreakdown {
+				round: U64(window.round),
+				required_bond_size: U128(window.required_bond_size),
+				end_time: U64(window.end_time),
+				bonded_payout: window.outcome.as_ref().map(|outcome| outcome.iter().map(|numerator| U128(*numerator)).collect()),
+				correct: match (&window.outcome, self.finalized) {
+					(Some(outcome), true) => Some(*outcome == self.payout),
+					_ => None
+				},
+				unbonded_stake: U128(self.get_unbonded_stake(&window, account_id)),
+				stakes,
+			});
+		}
+
+		let claimed = self.claimed_earnings.get(account_id).is_some();
+		let unbonded_stake = self.get_total_unbonded_stake(account_id);
+		let dispute_earnings = self.get_dispute_earnings(account_id.to_string());
+
+		return StakeBreakdown {
+			windows,
+			finalized: self.finalized,
+			claimed,
+			unbonded_stake: U128(unbonded_stake),
+			dispute_earnings: U128(dispute_earnings),
+			entitlement: U128(if claimed {0} else {unbonded_stake + dispute_earnings}),
+		};
+	}
+
 	/** 
 	 * @notice Calculate the resolution/dispute earnings for a account_id
+	 * @dev Stake bonded on the final payout in any dispute round shares in the stake that was bonded on incorrect payouts in all rounds
 	 * @return Returns total earnings from particiapting in resolution/dispute
 	 */
 	fn get_dispute_earnings(
@@ -652,24 +1345,22 @@ impl Market {
 		let mut total_correctly_staked = 0;
 		let mut total_incorrectly_staked = 0;
 
-		let winning_outcome_id = self.to_numerical_outcome(self.winning_outcome);
-		
 		/* Loop through all resolution_windows */
 		for window in self.resolution_windows.iter() {
 			/* check if round = 0 - which is the resolution round */
 			if window.round == 0 {
 				
-				let claimable_if_invalid = match self.winning_outcome {
-					None => self.total_feeable_if_invalid,
-					_ => 0
+				let claimable_if_invalid = match self.is_valid() {
+					false => self.total_feeable_if_invalid,
+					true => 0
 				};
 
 				/* Calculate how much the total fee payout will be */
 				let total_resolution_fee = self.resolution_fee_percentage * (self.filled_volume + claimable_if_invalid) / 10000;
 		
-				/* Check if the outcome that a resolution bond was staked on coresponds with the finalized outcome */
-				if self.winning_outcome == window.outcome {
-					/* check if the user participated in this outcome */
+				/* Check if the payout that a resolution bond was staked on coresponds with the finalized payout */
+				if window.outcome.as_ref() == Some(&self.payout) {
+					/* check if the user participated in this payout */
 					let resolution_participation = !window.participants_to_outcome_to_stake.get(&account_id).is_none();
 					
 					if resolution_participation {
@@ -677,7 +1368,7 @@ impl Market {
 						let correct_outcome_participation = window.participants_to_outcome_to_stake
 						.get(&account_id)
 						.unwrap()
-						.get(&self.to_numerical_outcome(self.winning_outcome))
+						.get(&self.payout)
 						.unwrap_or(0);
 
 						if correct_outcome_participation > 0 {
@@ -686,19 +1377,17 @@ impl Market {
 						}
 						
 					} 
-				} else {
-					/* If the initial resolution bond wasn't staked on the correct outcome, devide the resolution fee amongst disputors */
+				} else if window.outcome.is_some() {
+					/* If the initial resolution bond wasn't staked on the correct payout, devide the resolution fee amongst disputors */
 					total_incorrectly_staked += total_resolution_fee + window.required_bond_size;
 				}
 			} else {
 				/* If it isn't the first round calculate according to escalation game */
-				let window_outcome_id = self.to_numerical_outcome(window.outcome);
-
-				if window_outcome_id == winning_outcome_id {
+				if window.outcome.as_ref() == Some(&self.payout) {
 					let round_participation = window.participants_to_outcome_to_stake
 					.get(&account_id)
-					.unwrap_or(UnorderedMap::new(format!("market:{}:staked_per_outcome:{}:{}", self.id, window.round, account_id).as_bytes().to_vec()))
-					.get(&winning_outcome_id)
+					.unwrap_or(UnorderedMap::new(format!("market:{}:participants_to_outcome_to_stake:{}:{}", self.id, window.round, account_id).as_bytes().to_vec()))
+					.get(&self.payout)
 					.unwrap_or(0);
 
 					user_correctly_staked += round_participation;
@@ -710,37 +1399,81 @@ impl Market {
 			}
 		}
 
-		if total_correctly_staked == 0 || total_incorrectly_staked == 0 || user_correctly_staked == 0 {return resolution_reward}
+		/* If there's no incorrect stake to share, e.g. because an oracle report without stake was disputed, correct stakers still get their stake back */
+		if total_correctly_staked == 0 || user_correctly_staked == 0 {return resolution_reward}
 
 		/* Declare decimals to make sure smallers takers still are rewarded */
 		let decimals = 1e16 as u128;
-		/* Calculate profit from participating in disputes */
-		let profit = ((total_incorrectly_staked * decimals) / (total_correctly_staked / user_correctly_staked)) / decimals; 
+		/* Calculate profit from participating in disputes, the user's share is calculated first so stake spread over multiple rounds isn't rounded down */
+		let user_share = user_correctly_staked * decimals / total_correctly_staked;
+		let profit = total_incorrectly_staked * user_share / decimals;
 
 		return profit + user_correctly_staked + resolution_reward;
 	}
 
 	/**
-	 * @notice Convert u64 -> U64 within the option
+	 * @notice Checks if the market's current payout is valid, an empty payout means the market is (or will be) resoluted as invalid
 	 */
-	fn to_loggable_winning_outcome(
-		&self, 
-		winning_outcome: Option<u64>
-	) -> Option<U64> {
-		return match winning_outcome {
-			Some(outcome) => Some(U64(outcome)),
-			None => None
-		};
+	pub fn is_valid(
+		&self
+	) -> bool {
+		return !self.payout.is_empty();
 	}
 
 	/**
-	 * @notice Convert winning_outcome (Option<u64>) -> u64 where None = self.outcomes
+	 * @notice Returns the winning outcome if the payout assigns the full payout to a single outcome
+	 * @return Returns None if the payout is invalid or split between multiple outcomes
 	 */
-	pub fn to_numerical_outcome(
-		&self, 
-		outcome: Option<u64>, 
-	) -> u64 {
-		return outcome.unwrap_or(self.outcomes);
+	pub fn get_winning_outcome(
+		&self
+	) -> Option<u64> {
+		for (outcome, numerator) in self.payout.iter().enumerate() {
+			if *numerator == PAYOUT_DENOMINATOR {
+				return Some(outcome as u64);
+			}
+		}
+		return None;
+	}
+
+	/**
+	 * @notice Converts the outcome or payout numerators provided by a staker / judge into a payout
+	 * @dev Panics if both a winning_outcome and payout_numerators are provided
+	 *  Panics if the winning_outcome is out of range, for scalar markets the winning_outcome represents the resolution value and needs to be within the market's bounds
+	 *  Panics if the payout_numerators don't contain a numerator per outcome or don't add up to PAYOUT_DENOMINATOR
+	 * @return Returns the payout, None (invalid) is converted into an empty payout
+	 */
+	pub fn to_payout(
+		&self,
+		winning_outcome: Option<u64>,
+		payout_numerators: Option<Vec<u128>>
+	) -> Payout {
+		if let Some(numerators) = payout_numerators {
+			assert!(winning_outcome.is_none(), "can't provide both a winning outcome and payout numerators");
+			assert_eq!(numerators.len() as u64, self.outcomes, "payout needs a numerator for each outcome");
+			let total: u128 = numerators.iter().sum();
+			assert_eq!(total, PAYOUT_DENOMINATOR, "payout numerators need to add up to the payout denominator");
+			return numerators;
+		}
+
+		let outcome = match winning_outcome {
+			Some(outcome) => outcome,
+			None => return vec![]
+		};
+
+		return match &self.scalar_bounds {
+			Some(bounds) => {
+				assert!(outcome >= bounds.min && outcome <= bounds.max, "invalid winning outcome");
+				/* Long shares receive the part of the payout relative to where the value landed within the bounds, short shares get the remainder */
+				let long_numerator = (outcome - bounds.min) as u128 * PAYOUT_DENOMINATOR / (bounds.max - bounds.min) as u128;
+				vec![PAYOUT_DENOMINATOR - long_numerator, long_numerator]
+			},
+			None => {
+				assert!(outcome < self.outcomes, "invalid winning outcome");
+				let mut payout = vec![0; self.outcomes as usize];
+				payout[outcome as usize] = PAYOUT_DENOMINATOR;
+				payout
+			}
+		};
 	}
 }
 
@@ -752,4 +1485,4 @@ impl Default for Market {
 	fn default() -> Self {
 		panic!("No default state available init with ::new"); 
 	}
-}
\ No newline at end of file
+}
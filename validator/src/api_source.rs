@@ -0,0 +1,143 @@
+use serde::Deserialize;
+use serde_json::Value;
+
+/**
+ * @notice Describes where the value a market resolutes to can be fetched
+ * @dev A market's api_source is expected to be a JSON object of this shape, e.g.
+ *  `{"endpoint": "https://api.example.com/price", "path": "$.data.prices[0].usd"}`
+ */
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ApiSource {
+	pub endpoint: String,
+	pub path: String,
+}
+
+impl ApiSource {
+	/**
+	 * @notice Parses a market's api_source
+	 * @return Returns an error if the api_source isn't a valid api source object
+	 */
+	pub fn parse(api_source: &str) -> Result<Self, String> {
+		return serde_json::from_str(api_source).map_err(|err| format!("invalid api_source `{}`: {}", api_source, err));
+	}
+}
+
+/**
+ * @notice Mirrors the protocol's `OutcomeMapping`, how a resolution source's value maps to the winning outcome
+ */
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutcomeMapping {
+	Direct,
+	Match(Vec<(String, u64)>),
+	Thresholds(Vec<u64>),
+}
+
+/**
+ * @notice Mirrors the protocol's `ResolutionSource`, the structured specification markets can be created with
+ * @dev Markets with a resolution source are resoluted according to it, the api_source is only used for markets without one
+ */
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ResolutionSource {
+	pub endpoint: String,
+	pub path: String,
+	pub mapping: OutcomeMapping,
+	pub timestamp: u64,
+}
+
+#[derive(Debug, PartialEq)]
+enum Segment {
+	Key(String),
+	Index(usize),
+}
+
+/**
+ * @notice Splits a path expression into keys and array indices
+ * @dev Supports both `$.data.prices[0].usd` and `data.prices.0.usd`
+ */
+fn parse_path(path: &str) -> Result<Vec<Segment>, String> {
+	let trimmed = path.trim();
+	let trimmed = trimmed.trim_start_matches('$');
+	let mut segments = vec![];
+
+	for part in trimmed.split('.') {
+		if part.is_empty() {
+			continue;
+		}
+
+		let (key, mut rest) = match part.find('[') {
+			Some(index) => (&part[..index], &part[index..]),
+			None => (part, "")
+		};
+
+		if !key.is_empty() {
+			segments.push(Segment::Key(key.to_string()));
+		}
+
+		while !rest.is_empty() {
+			if !rest.starts_with('[') {
+				return Err(format!("unexpected `{}` in path `{}`", rest, path));
+			}
+			let end = rest.find(']').ok_or_else(|| format!("unclosed bracket in path `{}`", path))?;
+			let index = rest[1..end].trim().parse::<usize>().map_err(|_| format!("invalid array index in path `{}`", path))?;
+			segments.push(Segment::Index(index));
+			rest = &rest[end + 1..];
+		}
+	}
+
+	return Ok(segments);
+}
+
+/**
+ * @notice Selects the value at `path` in a JSON document
+ * @return Returns an error if the path is malformed or doesn't point to a value
+ */
+pub fn select<'a>(document: &'a Value, path: &str) -> Result<&'a Value, String> {
+	let mut current = document;
+
+	for segment in parse_path(path)? {
+		let next = match (&segment, current) {
+			(Segment::Key(key), Value::Object(map)) => map.get(key),
+			/* Numeric keys can be used to index arrays in dot notation */
+			(Segment::Key(key), Value::Array(values)) => key.parse::<usize>().ok().and_then(|index| values.get(index)),
+			(Segment::Index(index), Value::Array(values)) => values.get(*index),
+			_ => None
+		};
+
+		current = next.ok_or_else(|| format!("path `{}` doesn't exist in response", path))?;
+	}
+
+	return Ok(current);
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_parse_api_source() {
+		let api_source = ApiSource::parse(r#"{"endpoint": "http://localhost:8080/price", "path": "$.price"}"#).unwrap();
+		assert_eq!(api_source.endpoint, "http://localhost:8080/price");
+		assert_eq!(api_source.path, "$.price");
+
+		assert!(ApiSource::parse("test").is_err());
+	}
+
+	#[test]
+	fn test_select() {
+		let document = json!({
+			"data": {
+				"prices": [{"usd": 101}, {"usd": 202}],
+				"winner": "Alice",
+			}
+		});
+
+		assert_eq!(select(&document, "$.data.prices[1].usd").unwrap(), &json!(202));
+		assert_eq!(select(&document, "data.prices.0.usd").unwrap(), &json!(101));
+		assert_eq!(select(&document, "$.data.winner").unwrap(), &json!("Alice"));
+		assert_eq!(select(&document, "$").unwrap(), &document);
+		assert!(select(&document, "$.data.prices[2].usd").is_err());
+		assert!(select(&document, "$.data.prices[x]").is_err());
+	}
+}
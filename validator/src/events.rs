@@ -0,0 +1,132 @@
+use serde_json::Value;
+use crate::api_source::ResolutionSource;
+
+/**
+ * @notice The parts of a `market_creation` log the validator needs to resolute a market
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarketCreation {
+	pub id: u64,
+	pub outcomes: u64,
+	pub outcome_tags: Vec<String>,
+	pub end_time: u64,
+	pub api_source: String,
+	pub resolution_source: Option<ResolutionSource>,
+	pub scalar_bounds: Option<(u64, u64)>,
+	pub parent: Option<u64>,
+	pub designated_reporter: Option<String>,
+	/* Duration in ms after end_time in which only the designated reporter or oracle can resolute, snapshotted from the protocol config at creation */
+	pub designated_reporter_window: u64,
+	pub oracle: Option<String>,
+}
+
+/**
+ * @notice Protocol logs that change how the validator treats a market, other logs are ignored
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+	MarketCreation(Box<MarketCreation>),
+	MarketEndTimeUpdated { market_id: u64, end_time: u64 },
+	NewResolutionWindow { market_id: u64, round: u64, required_bond_size: u128, end_time: u64 },
+	/* Emitted for both `market_resoluted` and `resolution_disputed`, the round's bond was filled for `payout` */
+	PayoutBonded { market_id: u64, round: u64, payout: Vec<u128> },
+	MarketFinalized { market_id: u64 },
+	MarketCancelled { market_id: u64 },
+}
+
+fn param<'a>(params: &'a Value, key: &str) -> Result<&'a Value, String> {
+	return params.get(key).ok_or_else(|| format!("log is missing `{}`", key));
+}
+
+/* U64 and U128 are logged as strings */
+pub fn to_u128(value: &Value) -> Result<u128, String> {
+	return match value {
+		Value::String(number) => number.parse::<u128>().map_err(|_| format!("`{}` isn't a number", number)),
+		Value::Number(number) => number.as_u64().map(|number| number as u128).ok_or_else(|| format!("`{}` isn't an unsigned number", number)),
+		_ => Err(format!("`{}` isn't a number", value))
+	};
+}
+
+fn to_u64(value: &Value) -> Result<u64, String> {
+	return to_u128(value).map(|number| number as u64);
+}
+
+fn to_string_option(value: Option<&Value>) -> Option<String> {
+	return value.and_then(|value| value.as_str()).map(|value| value.to_string());
+}
+
+fn parse_market_creation(params: &Value) -> Result<MarketCreation, String> {
+	let scalar_bounds = match params.get("scalar_bounds").and_then(|bounds| bounds.as_array()) {
+		Some(bounds) if bounds.len() == 2 => Some((to_u64(&bounds[0])?, to_u64(&bounds[1])?)),
+		_ => None
+	};
+	let parent = match params.get("parent").and_then(|parent| parent.as_array()) {
+		Some(parent) if !parent.is_empty() => Some(to_u64(&parent[0])?),
+		_ => None
+	};
+
+	return Ok(MarketCreation {
+		id: to_u64(param(params, "id")?)?,
+		outcomes: to_u64(param(params, "outcomes")?)?,
+		outcome_tags: params.get("outcome_tags").and_then(|tags| tags.as_array()).map(|tags| {
+			tags.iter().filter_map(|tag| tag.as_str()).map(|tag| tag.to_string()).collect()
+		}).unwrap_or_default(),
+		end_time: to_u64(param(params, "end_time")?)?,
+		api_source: to_string_option(params.get("api_source")).unwrap_or_default(),
+		resolution_source: match params.get("resolution_source") {
+			Some(source) if !source.is_null() => Some(serde_json::from_value(source.clone()).map_err(|err| format!("invalid resolution_source: {}", err))?),
+			_ => None
+		},
+		scalar_bounds,
+		parent,
+		designated_reporter: to_string_option(params.get("designated_reporter")),
+		designated_reporter_window: to_u64(param(params, "designated_reporter_window")?)?,
+		oracle: to_string_option(params.get("oracle")),
+	});
+}
+
+/**
+ * @notice Parses a single protocol log
+ * @dev Leading text before the JSON object (e.g. `Log [flux-protocol]: `) is skipped
+ * @return Returns Ok(None) for logs the validator doesn't act on
+ */
+pub fn parse_log(log: &str) -> Result<Option<Event>, String> {
+	let start = match log.find('{') {
+		Some(start) => start,
+		None => return Ok(None)
+	};
+	let log: Value = serde_json::from_str(&log[start..]).map_err(|err| format!("log isn't valid json: {}", err))?;
+	let log_type = log.get("type").and_then(|log_type| log_type.as_str()).unwrap_or_default();
+	let params = match log.get("params") {
+		Some(params) => params,
+		None => return Ok(None)
+	};
+
+	let event = match log_type {
+		"market_creation" => Event::MarketCreation(Box::new(parse_market_creation(params)?)),
+		"market_end_time_updated" => Event::MarketEndTimeUpdated {
+			market_id: to_u64(param(params, "market_id")?)?,
+			end_time: to_u64(param(params, "end_time")?)?,
+		},
+		"new_resolution_window" => Event::NewResolutionWindow {
+			market_id: to_u64(param(params, "market_id")?)?,
+			round: to_u64(param(params, "round")?)?,
+			required_bond_size: to_u128(param(params, "required_bond_size")?)?,
+			end_time: to_u64(param(params, "end_time")?)?,
+		},
+		"market_resoluted" | "resolution_disputed" => Event::PayoutBonded {
+			market_id: to_u64(param(params, "market_id")?)?,
+			round: to_u64(param(params, "round")?)?,
+			payout: param(params, "payout")?.as_array().ok_or("payout isn't an array")?.iter().map(to_u128).collect::<Result<Vec<u128>, String>>()?,
+		},
+		"market_finalized" => Event::MarketFinalized {
+			market_id: to_u64(param(params, "market_id")?)?,
+		},
+		"market_cancelled" => Event::MarketCancelled {
+			market_id: to_u64(param(params, "market_id")?)?,
+		},
+		_ => return Ok(None)
+	};
+
+	return Ok(Some(event));
+}
@@ -0,0 +1,39 @@
+use std::process::Command;
+
+/**
+ * @notice Fetches the body of an api_source endpoint
+ * @dev Implemented by `CurlFetcher` for real use, tests and local setups can plug in their own fetcher
+ */
+pub trait HttpFetcher {
+	fn get(&self, url: &str) -> Result<String, String>;
+}
+
+/**
+ * @notice Fetches endpoints through the `curl` binary
+ */
+pub struct CurlFetcher {
+	pub timeout_secs: u64,
+}
+
+impl Default for CurlFetcher {
+	fn default() -> Self {
+		return Self {
+			timeout_secs: 30,
+		};
+	}
+}
+
+impl HttpFetcher for CurlFetcher {
+	fn get(&self, url: &str) -> Result<String, String> {
+		let output = Command::new("curl")
+			.args(&["-sSfL", "--max-time", &self.timeout_secs.to_string(), url])
+			.output()
+			.map_err(|err| format!("couldn't run curl: {}", err))?;
+
+		if !output.status.success() {
+			return Err(format!("request to {} failed: {}", url, String::from_utf8_lossy(&output.stderr).trim()));
+		}
+
+		return String::from_utf8(output.stdout).map_err(|err| format!("response from {} isn't valid utf8: {}", url, err));
+	}
+}
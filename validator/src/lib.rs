@@ -0,0 +1,12 @@
+/* Explicit returns and tab indented doc comments are the protocol code style */
+#![allow(clippy::needless_return, clippy::tabs_in_doc_comments)]
+
+pub mod api_source;
+pub mod events;
+pub mod fetcher;
+pub mod near_client;
+pub mod validator;
+
+pub use fetcher::{HttpFetcher, CurlFetcher};
+pub use near_client::{NearClient, NearCliClient};
+pub use validator::{Validator, ValidatorConfig, Action, StepReport};
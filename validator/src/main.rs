@@ -0,0 +1,77 @@
+/* Explicit returns and tab indented doc comments are the protocol code style */
+#![allow(clippy::needless_return, clippy::tabs_in_doc_comments)]
+
+use flux_validator::{CurlFetcher, NearCliClient, Validator, ValidatorConfig};
+use std::{
+	collections::HashMap,
+	env,
+	path::PathBuf,
+	process,
+	thread,
+	time::Duration,
+};
+
+const USAGE: &str = "usage: flux-validator --contract <account_id> --account <account_id> --log-file <path> --stake <amount>
+	[--max-dispute-stake <amount>] [--poll-interval <secs>]";
+
+fn parse_args() -> Result<HashMap<String, String>, String> {
+	let mut args = HashMap::new();
+	let mut raw_args = env::args().skip(1);
+
+	while let Some(flag) = raw_args.next() {
+		if !flag.starts_with("--") {
+			return Err(format!("unexpected argument `{}`", flag));
+		}
+		let value = raw_args.next().ok_or_else(|| format!("{} is missing a value", flag))?;
+		args.insert(flag[2..].to_string(), value);
+	}
+
+	return Ok(args);
+}
+
+fn required(args: &HashMap<String, String>, key: &str) -> Result<String, String> {
+	return args.get(key).cloned().ok_or_else(|| format!("--{} is required", key));
+}
+
+fn number<T: std::str::FromStr>(args: &HashMap<String, String>, key: &str, default: Option<T>) -> Result<T, String> {
+	return match args.get(key) {
+		Some(value) => value.parse::<T>().map_err(|_| format!("--{} has to be a number", key)),
+		None => default.ok_or_else(|| format!("--{} is required", key))
+	};
+}
+
+fn run() -> Result<(), String> {
+	let args = parse_args()?;
+	let account_id = required(&args, "account")?;
+	let resolute_stake: u128 = number(&args, "stake", None)?;
+	let config = ValidatorConfig {
+		account_id: account_id.to_string(),
+		resolute_stake,
+		max_dispute_stake: number(&args, "max-dispute-stake", Some(resolute_stake * 2))?,
+	};
+	let poll_interval: u64 = number(&args, "poll-interval", Some(10))?;
+	let client = NearCliClient::new(required(&args, "contract")?, account_id, PathBuf::from(required(&args, "log-file")?));
+	let mut validator = Validator::new(CurlFetcher::default(), client, config);
+
+	loop {
+		match validator.step() {
+			Ok(report) => {
+				for action in report.actions {
+					println!("submitted {:?}", action);
+				}
+				for err in report.errors {
+					eprintln!("{}", err);
+				}
+			},
+			Err(err) => eprintln!("{}", err)
+		}
+		thread::sleep(Duration::from_secs(poll_interval));
+	}
+}
+
+fn main() {
+	if let Err(err) = run() {
+		eprintln!("{}\n{}", err, USAGE);
+		process::exit(1);
+	}
+}
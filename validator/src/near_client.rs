@@ -0,0 +1,114 @@
+use serde_json::Value;
+use std::{
+	fs::File,
+	io::{Read, Seek, SeekFrom},
+	path::PathBuf,
+	process::Command,
+	time::{SystemTime, UNIX_EPOCH},
+};
+
+/**
+ * @notice The validator's connection to the protocol contract
+ * @dev Implemented by `NearCliClient` for real use, tests plug in a runtime stand-in
+ */
+pub trait NearClient {
+	/* Returns the logs the protocol contract emitted since the previous call */
+	fn poll_logs(&mut self) -> Result<Vec<String>, String>;
+	/* Returns the current time in ms, markets can be resoluted once this passes their end_time */
+	fn timestamp_ms(&mut self) -> Result<u64, String>;
+	/* Calls a change method on the protocol contract */
+	fn call(&mut self, method: &str, args: &Value) -> Result<(), String>;
+	/* Calls a view method on the protocol contract and returns its result */
+	fn view(&mut self, method: &str, args: &Value) -> Result<Value, String>;
+}
+
+/**
+ * @notice Submits transactions through `near-cli` and reads the contract's logs from a file
+ * @dev NEAR doesn't serve contract logs through near-cli, the log file has to be appended to by an indexer
+ *  that writes every log of the contract as one line in execution order, see "Log file" in the README
+ */
+pub struct NearCliClient {
+	pub contract_id: String,
+	pub account_id: String,
+	pub log_file: PathBuf,
+	pub gas: u64,
+	offset: u64,
+}
+
+impl NearCliClient {
+	pub fn new(contract_id: String, account_id: String, log_file: PathBuf) -> Self {
+		return Self {
+			contract_id,
+			account_id,
+			log_file,
+			gas: 300_000_000_000_000,
+			offset: 0,
+		};
+	}
+}
+
+impl NearClient for NearCliClient {
+	fn poll_logs(&mut self) -> Result<Vec<String>, String> {
+		let mut file = File::open(&self.log_file).map_err(|err| format!("couldn't open {}: {}", self.log_file.display(), err))?;
+		file.seek(SeekFrom::Start(self.offset)).map_err(|err| err.to_string())?;
+		let mut appended = String::new();
+		file.read_to_string(&mut appended).map_err(|err| err.to_string())?;
+
+		/* Only consume complete lines, the indexer might still be writing the last one */
+		let complete = match appended.rfind('\n') {
+			Some(index) => &appended[..index + 1],
+			None => return Ok(vec![])
+		};
+		self.offset += complete.len() as u64;
+
+		return Ok(complete.lines().map(|line| line.trim().to_string()).filter(|line| !line.is_empty()).collect());
+	}
+
+	fn timestamp_ms(&mut self) -> Result<u64, String> {
+		let now = SystemTime::now().duration_since(UNIX_EPOCH).map_err(|err| err.to_string())?;
+		return Ok(now.as_millis() as u64);
+	}
+
+	fn call(&mut self, method: &str, args: &Value) -> Result<(), String> {
+		let output = Command::new("near")
+			.args(&[
+				"call",
+				&self.contract_id,
+				method,
+				&args.to_string(),
+				"--accountId",
+				&self.account_id,
+				"--gas",
+				&self.gas.to_string(),
+			])
+			.output()
+			.map_err(|err| format!("couldn't run near-cli: {}", err))?;
+
+		if !output.status.success() {
+			return Err(format!("{} failed: {}", method, String::from_utf8_lossy(&output.stderr).trim()));
+		}
+
+		return Ok(());
+	}
+
+	fn view(&mut self, method: &str, args: &Value) -> Result<Value, String> {
+		let output = Command::new("near")
+			.args(&[
+				"view",
+				&self.contract_id,
+				method,
+				&args.to_string(),
+			])
+			.output()
+			.map_err(|err| format!("couldn't run near-cli: {}", err))?;
+
+		if !output.status.success() {
+			return Err(format!("{} failed: {}", method, String::from_utf8_lossy(&output.stderr).trim()));
+		}
+
+		/* near-cli prints the result on the last line, strings are wrapped in single quotes instead of double quotes */
+		let stdout = String::from_utf8_lossy(&output.stdout);
+		let result = stdout.lines().rev().map(|line| line.trim()).find(|line| !line.is_empty()).ok_or_else(|| format!("{} returned nothing", method))?;
+		return Ok(serde_json::from_str(result).unwrap_or_else(|_| Value::String(result.trim_matches('\'').to_string())));
+	}
+}
@@ -0,0 +1,591 @@
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashSet};
+use crate::{
+	api_source::{self, ApiSource, OutcomeMapping},
+	events::{self, Event, MarketCreation},
+	fetcher::HttpFetcher,
+	near_client::NearClient,
+};
+
+pub const PAYOUT_DENOMINATOR: u128 = 10000;
+
+/**
+ * @notice Settings of a validator instance
+ */
+#[derive(Debug, Clone)]
+pub struct ValidatorConfig {
+	/* The account the validator submits transactions from, needs an allowance on the protocol contract */
+	pub account_id: String,
+	/* The validator stakes a market's full resolute bond, it won't resolute markets whose bond exceeds this */
+	pub resolute_stake: u128,
+	/* The validator won't dispute if a round's bond exceeds this */
+	pub max_dispute_stake: u128,
+}
+
+/**
+ * @notice A transaction the validator submitted
+ * @dev winning_outcome is None for invalid markets, for scalar markets it's the resolution value
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action {
+	Resolute { market_id: u64, winning_outcome: Option<u64>, stake: u128 },
+	Dispute { market_id: u64, winning_outcome: Option<u64>, stake: u128 },
+}
+
+/**
+ * @notice The outcome of a single validator step
+ */
+#[derive(Debug, Default, PartialEq)]
+pub struct StepReport {
+	/* The transactions that were submitted successfully */
+	pub actions: Vec<Action>,
+	/* Logs that couldn't be parsed and markets that couldn't be acted on, these don't stop the validator */
+	pub errors: Vec<String>,
+}
+
+struct ResolutionWindow {
+	round: u64,
+	required_bond_size: u128,
+	end_time: u64,
+}
+
+struct TrackedMarket {
+	market: MarketCreation,
+	/* The currently open resolution window, None once the market is escalated to the judge council */
+	window: Option<ResolutionWindow>,
+	/* The payout of the most recently bonded resolution or dispute */
+	bonded_payout: Option<Vec<u128>>,
+	/* The outcome according to the api_source, cached once it has been fetched */
+	winning_outcome: Option<Option<u64>>,
+	/* What the validator staked in the resolution round, tops up if the bond grew before the stake came in */
+	resolute_staked: u128,
+	/* The last dispute round the validator staked in, makes sure it only disputes once per round */
+	staked_round: Option<u64>,
+}
+
+/**
+ * @notice Follows the protocol's logs and resolutes or disputes markets according to their api_source
+ */
+pub struct Validator<F: HttpFetcher, C: NearClient> {
+	pub fetcher: F,
+	pub client: C,
+	pub config: ValidatorConfig,
+	markets: BTreeMap<u64, TrackedMarket>,
+	finalized: HashSet<u64>,
+}
+
+/**
+ * @notice Converts the value found at an api_source's path into a winning outcome
+ * @dev null resolutes as invalid, scalar values are clamped to the market's bounds,
+ *  strings are matched against the outcome tags (yes/no for binary markets without tags)
+ * @return Returns the winning outcome, None if the market should resolute as invalid
+ */
+pub fn to_winning_outcome(market: &MarketCreation, value: &Value) -> Result<Option<u64>, String> {
+	if value.is_null() {
+		return Ok(None);
+	}
+
+	if let Some((min, max)) = market.scalar_bounds {
+		let number = value.as_f64()
+			.or_else(|| value.as_str().and_then(|number| number.trim().parse::<f64>().ok()))
+			.ok_or_else(|| format!("`{}` isn't a valid scalar value", value))?;
+		return Ok(Some((number.max(min as f64).min(max as f64)) as u64));
+	}
+
+	let outcome = match value {
+		Value::Bool(outcome) if market.outcomes == 2 => Some(*outcome as u64),
+		Value::Number(number) => number.as_u64(),
+		Value::String(tag) => {
+			let tag = tag.trim();
+			market.outcome_tags.iter().position(|outcome_tag| outcome_tag.eq_ignore_ascii_case(tag)).map(|outcome| outcome as u64).or_else(|| {
+				if market.outcomes == 2 && market.outcome_tags.is_empty() {
+					match tag.to_ascii_lowercase().as_str() {
+						"no" | "false" => Some(0),
+						"yes" | "true" => Some(1),
+						_ => None
+					}
+				} else {
+					None
+				}
+			})
+		},
+		_ => None
+	};
+
+	return match outcome {
+		Some(outcome) if outcome < market.outcomes => Ok(Some(outcome)),
+		_ => Err(format!("`{}` doesn't map to an outcome of market {}", value, market.id))
+	};
+}
+
+/**
+ * @notice Converts the value found at a resolution source's path into a winning outcome according to the source's mapping
+ * @dev Values that don't match any value of a match mapping resolute the market as invalid
+ */
+pub fn map_to_winning_outcome(market: &MarketCreation, mapping: &OutcomeMapping, value: &Value) -> Result<Option<u64>, String> {
+	return match mapping {
+		OutcomeMapping::Direct => to_winning_outcome(market, value),
+		OutcomeMapping::Match(values) => {
+			let value = match value {
+				Value::String(value) => value.trim().to_string(),
+				Value::Null => return Ok(None),
+				_ => value.to_string()
+			};
+			Ok(values.iter().find(|(match_value, _)| match_value.eq_ignore_ascii_case(&value)).map(|(_, outcome)| *outcome))
+		},
+		OutcomeMapping::Thresholds(thresholds) => {
+			let number = value.as_f64()
+				.or_else(|| value.as_str().and_then(|number| number.trim().parse::<f64>().ok()))
+				.ok_or_else(|| format!("`{}` can't be compared to thresholds", value))?;
+			Ok(Some(thresholds.iter().filter(|threshold| number >= **threshold as f64).count() as u64))
+		}
+	};
+}
+
+/**
+ * @notice Mirrors the protocol's `Market::to_payout` so bonded payouts can be compared to the validator's outcome
+ */
+pub fn to_payout(market: &MarketCreation, winning_outcome: Option<u64>) -> Vec<u128> {
+	let outcome = match winning_outcome {
+		Some(outcome) => outcome,
+		None => return vec![]
+	};
+
+	return match market.scalar_bounds {
+		Some((min, max)) => {
+			let long_numerator = (outcome - min) as u128 * PAYOUT_DENOMINATOR / (max - min) as u128;
+			vec![PAYOUT_DENOMINATOR - long_numerator, long_numerator]
+		},
+		None => {
+			let mut payout = vec![0; market.outcomes as usize];
+			payout[outcome as usize] = PAYOUT_DENOMINATOR;
+			payout
+		}
+	};
+}
+
+impl<F: HttpFetcher, C: NearClient> Validator<F, C> {
+	pub fn new(fetcher: F, client: C, config: ValidatorConfig) -> Self {
+		return Self {
+			fetcher,
+			client,
+			config,
+			markets: BTreeMap::new(),
+			finalized: HashSet::new(),
+		};
+	}
+
+	/**
+	 * @notice Updates the validator's view of the protocol with a single event
+	 */
+	pub fn handle_event(&mut self, event: Event) {
+		match event {
+			Event::MarketCreation(market) => {
+				self.markets.insert(market.id, TrackedMarket {
+					market: *market,
+					window: None,
+					bonded_payout: None,
+					winning_outcome: None,
+					resolute_staked: 0,
+					staked_round: None,
+				});
+			},
+			Event::MarketEndTimeUpdated { market_id, end_time } => {
+				if let Some(tracked) = self.markets.get_mut(&market_id) {
+					tracked.market.end_time = end_time;
+				}
+			},
+			Event::NewResolutionWindow { market_id, round, required_bond_size, end_time } => {
+				if let Some(tracked) = self.markets.get_mut(&market_id) {
+					tracked.window = Some(ResolutionWindow { round, required_bond_size, end_time });
+				}
+			},
+			Event::PayoutBonded { market_id, payout, .. } => {
+				if let Some(tracked) = self.markets.get_mut(&market_id) {
+					/* The bonded window is closed, if the dispute isn't escalated to the judge council a new window follows */
+					tracked.bonded_payout = Some(payout);
+					tracked.window = None;
+				}
+			},
+			Event::MarketFinalized { market_id } | Event::MarketCancelled { market_id } => {
+				self.markets.remove(&market_id);
+				self.finalized.insert(market_id);
+			},
+		}
+	}
+
+	/**
+	 * @notice Processes new logs and submits a transaction for every market the validator has to act on
+	 * @dev Errors of single logs or markets are collected in the report, only failing to poll logs or the time fails the step
+	 * @return Returns the actions that were submitted successfully and the errors that were skipped
+	 */
+	pub fn step(&mut self) -> Result<StepReport, String> {
+		let mut report = StepReport::default();
+
+		for log in self.client.poll_logs()? {
+			match events::parse_log(&log) {
+				Ok(Some(event)) => self.handle_event(event),
+				Ok(None) => {},
+				Err(err) => report.errors.push(format!("skipping log `{}`: {}", log, err))
+			}
+		}
+
+		let now = self.client.timestamp_ms()?;
+		let market_ids: Vec<u64> = self.markets.keys().cloned().collect();
+
+		for market_id in market_ids {
+			let action = match self.next_action(market_id, now) {
+				Ok(Some(action)) => action,
+				Ok(None) => continue,
+				Err(err) => {
+					report.errors.push(format!("market {}: {}", market_id, err));
+					continue;
+				}
+			};
+
+			match self.submit(&action) {
+				Ok(()) => {
+					let tracked = self.markets.get_mut(&market_id).expect("market is tracked");
+					match action {
+						Action::Resolute { stake, .. } => tracked.resolute_staked += stake,
+						Action::Dispute { .. } => tracked.staked_round = tracked.window.as_ref().map(|window| window.round),
+					}
+					report.actions.push(action);
+				},
+				Err(err) => report.errors.push(format!("market {}: {}", market_id, err))
+			}
+		}
+
+		return Ok(report);
+	}
+
+	fn submit(&mut self, action: &Action) -> Result<(), String> {
+		let (method, market_id, winning_outcome, stake) = match action {
+			Action::Resolute { market_id, winning_outcome, stake } => ("resolute_market", market_id, winning_outcome, stake),
+			Action::Dispute { market_id, winning_outcome, stake } => ("dispute_market", market_id, winning_outcome, stake),
+		};
+
+		let args = json!({
+			"market_id": market_id.to_string(),
+			"winning_outcome": winning_outcome.map(|outcome| outcome.to_string()),
+			"stake": stake.to_string(),
+		});
+
+		return self.client.call(method, &args);
+	}
+
+	/**
+	 * @notice Fetches a market's resolution source, or api_source if it has none, and converts the result into a winning outcome
+	 */
+	fn evaluate(&self, market: &MarketCreation) -> Result<Option<u64>, String> {
+		if let Some(source) = &market.resolution_source {
+			let body = self.fetcher.get(&source.endpoint)?;
+			let document: Value = serde_json::from_str(&body).map_err(|err| format!("response from {} isn't valid json: {}", source.endpoint, err))?;
+			let value = api_source::select(&document, &source.path)?;
+			return map_to_winning_outcome(market, &source.mapping, value);
+		}
+
+		let api_source = ApiSource::parse(&market.api_source)?;
+		let body = self.fetcher.get(&api_source.endpoint)?;
+		let document: Value = serde_json::from_str(&body).map_err(|err| format!("response from {} isn't valid json: {}", api_source.endpoint, err))?;
+		let value = api_source::select(&document, &api_source.path)?;
+		return to_winning_outcome(market, value);
+	}
+
+	fn next_action(&mut self, market_id: u64, now: u64) -> Result<Option<Action>, String> {
+		let tracked = self.markets.get(&market_id).expect("market is tracked");
+		let market = &tracked.market;
+
+		if now < market.end_time {
+			return Ok(None);
+		}
+		/* The resolution source's value is only final once its timestamp has passed */
+		if market.resolution_source.as_ref().map(|source| now < source.timestamp).unwrap_or(false) {
+			return Ok(None);
+		}
+		if let Some(parent_id) = market.parent {
+			if !self.finalized.contains(&parent_id) {
+				return Ok(None);
+			}
+		}
+		let window_round = tracked.window.as_ref().map(|window| window.round);
+		if window_round.is_some() && tracked.staked_round == window_round {
+			return Ok(None);
+		}
+		/* Markets with a designated reporter or an oracle can't be resoluted by the public during the market's own reporting window */
+		let exclusive_reporter = market.designated_reporter.is_some() || market.oracle.is_some();
+		if tracked.bonded_payout.is_none() && exclusive_reporter && market.designated_reporter.as_ref() != Some(&self.config.account_id) && now < market.end_time + market.designated_reporter_window {
+			return Ok(None);
+		}
+
+		let winning_outcome = match tracked.winning_outcome {
+			Some(winning_outcome) => winning_outcome,
+			None => {
+				let winning_outcome = self.evaluate(market)?;
+				self.markets.get_mut(&market_id).expect("market is tracked").winning_outcome = Some(winning_outcome);
+				winning_outcome
+			}
+		};
+		let tracked = self.markets.get(&market_id).expect("market is tracked");
+
+		let bonded_payout = match &tracked.bonded_payout {
+			Some(bonded_payout) => bonded_payout,
+			None => {
+				/* The resolute bond grows with the filled volume so it's read from the protocol instead of the logs */
+				let bond = events::to_u128(&self.client.view("get_resolute_bond", &json!({ "market_id": market_id.to_string() }))?)?;
+				if bond > self.config.resolute_stake {
+					return Err(format!("resolute bond of {} exceeds the max resolute stake", bond));
+				}
+				if tracked.resolute_staked >= bond {
+					return Ok(None);
+				}
+				return Ok(Some(Action::Resolute { market_id, winning_outcome, stake: bond - tracked.resolute_staked }));
+			}
+		};
+
+		if *bonded_payout == to_payout(&tracked.market, winning_outcome) {
+			return Ok(None);
+		}
+
+		/* Without an open window the dispute is escalated to the judge council */
+		let window = match &tracked.window {
+			Some(window) => window,
+			None => return Ok(None)
+		};
+		if now >= window.end_time {
+			return Ok(None);
+		}
+		if window.required_bond_size > self.config.max_dispute_stake {
+			return Err(format!("dispute bond of {} exceeds the max dispute stake", window.required_bond_size));
+		}
+
+		return Ok(Some(Action::Dispute { market_id, winning_outcome, stake: window.required_bond_size }));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::collections::HashMap;
+
+	struct MockFetcher {
+		responses: HashMap<String, String>,
+	}
+
+	impl HttpFetcher for MockFetcher {
+		fn get(&self, url: &str) -> Result<String, String> {
+			return self.responses.get(url).cloned().ok_or_else(|| format!("no response for {}", url));
+		}
+	}
+
+	/* Stand-in for the NEAR runtime, logs are pushed by the test and calls are recorded */
+	#[derive(Default)]
+	struct MockClient {
+		logs: Vec<String>,
+		now: u64,
+		calls: Vec<(String, Value)>,
+		resolute_bond: u128,
+	}
+
+	impl NearClient for MockClient {
+		fn poll_logs(&mut self) -> Result<Vec<String>, String> {
+			return Ok(self.logs.drain(..).collect());
+		}
+
+		fn timestamp_ms(&mut self) -> Result<u64, String> {
+			return Ok(self.now);
+		}
+
+		fn call(&mut self, method: &str, args: &Value) -> Result<(), String> {
+			self.calls.push((method.to_string(), args.clone()));
+			return Ok(());
+		}
+
+		fn view(&mut self, method: &str, _args: &Value) -> Result<Value, String> {
+			return match method {
+				"get_resolute_bond" => Ok(json!(self.resolute_bond.to_string())),
+				_ => Err(format!("no view for {}", method))
+			};
+		}
+	}
+
+	const ENDPOINT: &str = "http://localhost:8080/result";
+
+	fn init_validator(response: Value) -> Validator<MockFetcher, MockClient> {
+		let mut responses = HashMap::new();
+		responses.insert(ENDPOINT.to_string(), response.to_string());
+
+		return Validator::new(
+			MockFetcher { responses },
+			MockClient { resolute_bond: 5, ..MockClient::default() },
+			ValidatorConfig {
+				account_id: "validator".to_string(),
+				resolute_stake: 5,
+				max_dispute_stake: 100,
+			}
+		);
+	}
+
+	fn market_creation_log(id: u64, outcome_tags: Vec<&str>, end_time: u64) -> String {
+		let api_source = json!({ "endpoint": ENDPOINT, "path": "$.data.winner" }).to_string();
+		return json!({
+			"type": "market_creation",
+			"params": {
+				"id": id.to_string(),
+				"outcomes": if outcome_tags.is_empty() { "2".to_string() } else { outcome_tags.len().to_string() },
+				"outcome_tags": outcome_tags,
+				"end_time": end_time.to_string(),
+				"api_source": api_source,
+				"designated_reporter": null,
+				"designated_reporter_window": "1000",
+				"oracle": null,
+				"scalar_bounds": null,
+				"parent": null
+			}
+		}).to_string();
+	}
+
+	fn new_resolution_window_log(market_id: u64, round: u64, bond: u128, end_time: u64) -> String {
+		return json!({
+			"type": "new_resolution_window",
+			"params": { "market_id": market_id.to_string(), "round": round.to_string(), "required_bond_size": bond.to_string(), "end_time": end_time.to_string() }
+		}).to_string();
+	}
+
+	fn market_resoluted_log(market_id: u64, payout: Vec<&str>) -> String {
+		return json!({
+			"type": "market_resoluted",
+			"params": { "market_id": market_id.to_string(), "sender": "alice", "round": "0", "staked": "5", "payout": payout }
+		}).to_string();
+	}
+
+	#[test]
+	fn test_resolutes_after_end_time() {
+		let mut validator = init_validator(json!({ "data": { "winner": "Blue" } }));
+		validator.client.logs.push(format!("Log [flux-protocol]: {}", market_creation_log(0, vec!["Red", "Green", "Blue"], 100)));
+		validator.client.logs.push(new_resolution_window_log(0, 0, 0, 100));
+
+		validator.client.now = 99;
+		assert_eq!(validator.step().unwrap().actions, vec![]);
+
+		validator.client.now = 100;
+		assert_eq!(validator.step().unwrap().actions, vec![Action::Resolute { market_id: 0, winning_outcome: Some(2), stake: 5 }]);
+		assert_eq!(validator.client.calls[0], ("resolute_market".to_string(), json!({ "market_id": "0", "winning_outcome": "2", "stake": "5" })));
+
+		/* The validator only stakes once per round */
+		assert_eq!(validator.step().unwrap().actions, vec![]);
+		assert_eq!(validator.client.calls.len(), 1);
+	}
+
+	#[test]
+	fn test_waits_for_the_markets_reporting_window() {
+		let mut validator = init_validator(json!({ "data": { "winner": "yes" } }));
+		let mut log: Value = serde_json::from_str(&market_creation_log(0, vec![], 100)).unwrap();
+		log["params"]["designated_reporter"] = json!("carol");
+		log["params"]["designated_reporter_window"] = json!("500");
+		validator.client.logs.push(log.to_string());
+
+		validator.client.now = 599;
+		assert_eq!(validator.step().unwrap().actions, vec![]);
+
+		validator.client.now = 600;
+		assert_eq!(validator.step().unwrap().actions, vec![Action::Resolute { market_id: 0, winning_outcome: Some(1), stake: 5 }]);
+	}
+
+	#[test]
+	fn test_tops_up_resolute_bond() {
+		let mut validator = init_validator(json!({ "data": { "winner": "yes" } }));
+		validator.client.logs.push(market_creation_log(0, vec![], 100));
+		validator.client.now = 100;
+		validator.client.resolute_bond = 4;
+
+		assert_eq!(validator.step().unwrap().actions, vec![Action::Resolute { market_id: 0, winning_outcome: Some(1), stake: 4 }]);
+
+		/* The filled volume grew before the stake came in so it didn't fill the bond */
+		validator.client.resolute_bond = 5;
+		assert_eq!(validator.step().unwrap().actions, vec![Action::Resolute { market_id: 0, winning_outcome: Some(1), stake: 1 }]);
+
+		/* Bonds above the max resolute stake are skipped */
+		validator.client.resolute_bond = 6;
+		validator.client.logs.push(market_creation_log(1, vec![], 100));
+		let report = validator.step().unwrap();
+		assert_eq!(report.actions, vec![]);
+		assert_eq!(report.errors, vec![
+			"market 0: resolute bond of 6 exceeds the max resolute stake".to_string(),
+			"market 1: resolute bond of 6 exceeds the max resolute stake".to_string(),
+		]);
+		assert_eq!(validator.client.calls.len(), 2);
+	}
+
+	#[test]
+	fn test_disputes_incorrect_resolution() {
+		let mut validator = init_validator(json!({ "data": { "winner": "yes" } }));
+		validator.client.logs.push(market_creation_log(0, vec![], 100));
+		validator.client.logs.push(market_resoluted_log(0, vec!["10000", "0"]));
+		validator.client.logs.push(new_resolution_window_log(0, 1, 10, 500));
+		validator.client.now = 200;
+
+		assert_eq!(validator.step().unwrap().actions, vec![Action::Dispute { market_id: 0, winning_outcome: Some(1), stake: 10 }]);
+		assert_eq!(validator.client.calls[0].0, "dispute_market");
+	}
+
+	#[test]
+	fn test_agrees_with_correct_resolution() {
+		let mut validator = init_validator(json!({ "data": { "winner": true } }));
+		validator.client.logs.push(market_creation_log(0, vec![], 100));
+		validator.client.logs.push(market_resoluted_log(0, vec!["0", "10000"]));
+		validator.client.logs.push(new_resolution_window_log(0, 1, 10, 500));
+		validator.client.now = 200;
+
+		assert_eq!(validator.step().unwrap().actions, vec![]);
+		assert!(validator.client.calls.is_empty());
+	}
+
+	#[test]
+	fn test_resolutes_from_resolution_source() {
+		let mut validator = init_validator(json!({ "result": { "price": 2500.5 } }));
+		let mut log: Value = serde_json::from_str(&market_creation_log(0, vec!["Low", "Mid", "High"], 100)).unwrap();
+		log["params"]["resolution_source"] = json!({
+			"endpoint": ENDPOINT,
+			"path": "$.result.price",
+			"mapping": { "thresholds": [1000, 2000] },
+			"timestamp": 150,
+		});
+		validator.client.logs.push(log.to_string());
+
+		/* The market has ended but the resolution source's timestamp hasn't passed yet */
+		validator.client.now = 120;
+		assert_eq!(validator.step().unwrap().actions, vec![]);
+
+		validator.client.now = 150;
+		assert_eq!(validator.step().unwrap().actions, vec![Action::Resolute { market_id: 0, winning_outcome: Some(2), stake: 5 }]);
+	}
+
+	#[test]
+	fn test_match_mapping() {
+		let market = match events::parse_log(&market_creation_log(0, vec!["Home", "Away"], 100)).unwrap() {
+			Some(Event::MarketCreation(market)) => *market,
+			_ => panic!("expected a market creation")
+		};
+		let mapping = OutcomeMapping::Match(vec![("home_win".to_string(), 0), ("away_win".to_string(), 1)]);
+
+		assert_eq!(map_to_winning_outcome(&market, &mapping, &json!("AWAY_WIN")), Ok(Some(1)));
+		assert_eq!(map_to_winning_outcome(&market, &mapping, &json!("postponed")), Ok(None));
+	}
+
+	#[test]
+	fn test_to_winning_outcome() {
+		let categorical = events::parse_log(&market_creation_log(0, vec!["Red", "Green", "Blue"], 100)).unwrap();
+		let categorical = match categorical { Some(Event::MarketCreation(market)) => *market, _ => panic!("expected a market creation") };
+		assert_eq!(to_winning_outcome(&categorical, &json!("green")), Ok(Some(1)));
+		assert_eq!(to_winning_outcome(&categorical, &json!(2)), Ok(Some(2)));
+		assert_eq!(to_winning_outcome(&categorical, &Value::Null), Ok(None));
+		assert!(to_winning_outcome(&categorical, &json!("Purple")).is_err());
+		assert!(to_winning_outcome(&categorical, &json!(3)).is_err());
+
+		let scalar = MarketCreation { scalar_bounds: Some((100, 200)), outcomes: 2, outcome_tags: vec![], ..categorical };
+		assert_eq!(to_winning_outcome(&scalar, &json!(150.7)), Ok(Some(150)));
+		assert_eq!(to_winning_outcome(&scalar, &json!("250")), Ok(Some(200)));
+		assert_eq!(to_payout(&scalar, Some(150)), vec![5000, 5000]);
+	}
+}